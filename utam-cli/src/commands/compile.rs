@@ -0,0 +1,434 @@
+//! `utam compile` - compile UTAM JSON page objects to Rust
+//!
+//! Every input file is validated against the UTAM schema, compiled to a
+//! `.rs` file under the output directory, and registered in a generated
+//! `mod.rs` tree mirroring the input directory layout. Custom component
+//! types are resolved against all input files compiled together.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use console::style;
+use utam_compiler::{compile, CodeGenConfig, CompilerError, SchemaValidator, SymbolTable};
+
+use crate::commands::{
+    failure_result, input_files, report_compiler_error, report_error, resolve_inputs,
+};
+use crate::config::UtamConfig;
+use crate::error::{CliError, CliResult};
+use crate::files::{check_output_paths, InputFile};
+
+/// Output directory used when `--output` is not given
+pub const DEFAULT_OUTPUT_DIR: &str = "generated";
+
+/// Header written at the top of every generated `mod.rs`
+const MOD_HEADER: &str = "//! Generated by utam. Do not edit.\n";
+
+/// Failure compiling a single page object file
+#[derive(Debug)]
+pub(crate) enum FileError {
+    /// The file could not be read or its output could not be written
+    Io(CliError),
+    /// The file failed schema validation or code generation
+    Invalid { source: String, error: Box<CompilerError> },
+}
+
+impl FileError {
+    /// Print this failure to stderr
+    pub(crate) fn report(&self, path: &Path) {
+        match self {
+            FileError::Io(error) => report_error(error),
+            FileError::Invalid { source, error } => report_compiler_error(path, source, error),
+        }
+    }
+}
+
+/// Run `utam compile`
+///
+/// Inputs and output directory fall back to the config file when not given.
+///
+/// # Errors
+///
+/// * `CliError::ValidationFailed` - When any file fails validation or code generation
+/// * `CliError::IoFailed` - When any file cannot be read or written
+/// * `CliError::OutputCollision` - When two files would be compiled to the same output
+pub fn run(
+    inputs: &[PathBuf],
+    output: Option<&Path>,
+    config: &UtamConfig,
+    verbose: bool,
+) -> CliResult<()> {
+    let files = input_files(&resolve_inputs(inputs, config)?, config)?;
+    check_output_paths(&files)?;
+
+    let mut session = Session::new(output_dir(output, config), config.codegen_config(), verbose)?;
+    session.set_symbols(config.symbol_table(&files));
+    let failures = session.compile(&files);
+    session.write_modules()?;
+    session.print_summary(&failures, files.len());
+
+    failure_result(failures.io, failures.invalid, files.len())
+}
+
+/// Output directory: `--output`, then the config file, then [`DEFAULT_OUTPUT_DIR`]
+pub(crate) fn output_dir(output: Option<&Path>, config: &UtamConfig) -> PathBuf {
+    output
+        .or(config.output_directory.as_deref())
+        .unwrap_or_else(|| Path::new(DEFAULT_OUTPUT_DIR))
+        .to_path_buf()
+}
+
+/// Number of files that failed in a compilation pass, by failure kind
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Failures {
+    /// Files that could not be read or written
+    pub io: usize,
+    /// Files that failed validation or code generation
+    pub invalid: usize,
+}
+
+/// Compilation state shared by one-shot and watch mode
+///
+/// Holds the compiled schema validator and the module tree of everything
+/// written to the output directory so far.
+pub(crate) struct Session {
+    validator: SchemaValidator,
+    codegen: CodeGenConfig,
+    output_dir: PathBuf,
+    tree: ModuleTree,
+    verbose: bool,
+}
+
+impl Session {
+    /// Create a session writing to `output_dir`
+    ///
+    /// `codegen` provides the settings shared by every file; the module
+    /// name is set per file.
+    pub(crate) fn new(
+        output_dir: PathBuf,
+        codegen: CodeGenConfig,
+        verbose: bool,
+    ) -> CliResult<Self> {
+        Ok(Self {
+            validator: SchemaValidator::new()?,
+            codegen,
+            output_dir,
+            tree: ModuleTree::default(),
+            verbose,
+        })
+    }
+
+    /// Resolve custom components against the page objects in `symbols`
+    ///
+    /// References to other page objects that are neither in the table nor
+    /// in a mapped package then fail to compile. Without a table, custom
+    /// components resolve by their bare struct name.
+    pub(crate) fn set_symbols(&mut self, symbols: Option<SymbolTable>) {
+        self.codegen.symbols = symbols.map(Arc::new);
+    }
+
+    /// Output directory of this session
+    pub(crate) fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// Compile each file, reporting failures as they happen
+    pub(crate) fn compile(&mut self, files: &[InputFile]) -> Failures {
+        let mut failures = Failures::default();
+
+        for file in files {
+            match compile_file(&self.validator, &self.codegen, file, &self.output_dir) {
+                Ok(out_path) => {
+                    self.tree.insert(&file.module_path(), file.struct_name());
+                    if self.verbose {
+                        println!(
+                            "{} {} -> {}",
+                            style("compiled").green(),
+                            file.path.display(),
+                            out_path.display()
+                        );
+                    }
+                }
+                Err(error) => {
+                    error.report(&file.path);
+                    match error {
+                        FileError::Io(_) => failures.io += 1,
+                        FileError::Invalid { .. } => failures.invalid += 1,
+                    }
+                }
+            }
+        }
+
+        failures
+    }
+
+    /// Delete the generated module for a page object that no longer exists
+    pub(crate) fn remove(&mut self, file: &InputFile) -> CliResult<()> {
+        self.tree.remove(&file.module_path());
+        let out_path = self.output_dir.join(file.output_path());
+        match fs::remove_file(&out_path) {
+            Err(source) if source.kind() != std::io::ErrorKind::NotFound => {
+                Err(CliError::Io { path: out_path, source })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Write the `mod.rs` tree for everything compiled so far
+    pub(crate) fn write_modules(&self) -> CliResult<()> {
+        self.tree.write(&self.output_dir)
+    }
+
+    /// Print a one-line summary of a compilation pass
+    pub(crate) fn print_summary(&self, failures: &Failures, total: usize) {
+        println!(
+            "{} {} of {} file(s) to {}",
+            style("Compiled").green().bold(),
+            total - failures.io - failures.invalid,
+            total,
+            self.output_dir.display()
+        );
+    }
+}
+
+/// Validate and compile one page object, writing its `.rs` file under `output_dir`
+///
+/// Returns the path of the written file.
+pub(crate) fn compile_file(
+    validator: &SchemaValidator,
+    codegen: &CodeGenConfig,
+    file: &InputFile,
+    output_dir: &Path,
+) -> Result<PathBuf, FileError> {
+    let source = fs::read_to_string(&file.path)
+        .map_err(|source| FileError::Io(CliError::Io { path: file.path.clone(), source }))?;
+
+    let config = CodeGenConfig { module_name: Some(file.page_object_name()), ..codegen.clone() };
+    let code = match validator.validate_str(&source).and_then(|_| compile(&source, config)) {
+        Ok(code) => code,
+        Err(error) => return Err(FileError::Invalid { source, error: Box::new(error) }),
+    };
+
+    let out_path = output_dir.join(file.output_path());
+    write_file(&out_path, &code).map_err(FileError::Io)?;
+    Ok(out_path)
+}
+
+/// Write a file, creating its parent directories first
+pub(crate) fn write_file(path: &Path, contents: &str) -> CliResult<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|source| CliError::Io { path: parent.to_path_buf(), source })?;
+    }
+    fs::write(path, contents).map_err(|source| CliError::Io { path: path.to_path_buf(), source })
+}
+
+/// Module hierarchy of the generated output directory
+#[derive(Debug, Default)]
+pub(crate) struct ModuleTree {
+    /// Sub-directory modules by module name
+    dirs: BTreeMap<String, ModuleTree>,
+    /// Page object modules mapped to the struct they define
+    page_objects: BTreeMap<String, String>,
+}
+
+impl ModuleTree {
+    /// Register a page object at the given module path
+    pub(crate) fn insert(&mut self, module_path: &[String], struct_name: String) {
+        match module_path {
+            [] => {}
+            [module] => {
+                self.page_objects.insert(module.clone(), struct_name);
+            }
+            [dir, rest @ ..] => self.dirs.entry(dir.clone()).or_default().insert(rest, struct_name),
+        }
+    }
+
+    /// Remove a page object, dropping directories left empty
+    pub(crate) fn remove(&mut self, module_path: &[String]) {
+        match module_path {
+            [] => {}
+            [module] => {
+                self.page_objects.remove(module);
+            }
+            [dir, rest @ ..] => {
+                if let Some(child) = self.dirs.get_mut(dir) {
+                    child.remove(rest);
+                    if child.is_empty() {
+                        self.dirs.remove(dir);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns true if no page objects are registered at or below this level
+    fn is_empty(&self) -> bool {
+        self.dirs.is_empty() && self.page_objects.is_empty()
+    }
+
+    /// Render the `mod.rs` contents for this level of the tree
+    pub(crate) fn render(&self) -> String {
+        let mut out = String::from(MOD_HEADER);
+        out.push('\n');
+        for module in self.dirs.keys().chain(self.page_objects.keys()) {
+            out.push_str(&format!("pub mod {};\n", module));
+        }
+        if !self.page_objects.is_empty() {
+            out.push('\n');
+            for (module, struct_name) in &self.page_objects {
+                out.push_str(&format!("pub use {}::{};\n", module, struct_name));
+            }
+        }
+        out
+    }
+
+    /// Write `mod.rs` for this level and every sub-directory
+    pub(crate) fn write(&self, dir: &Path) -> CliResult<()> {
+        write_file(&dir.join("mod.rs"), &self.render())?;
+        for (module, child) in &self.dirs {
+            child.write(&dir.join(module))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn testdata(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("testdata").join(path)
+    }
+
+    fn temp_output(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("utam-cli-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_module_tree_render() {
+        let mut tree = ModuleTree::default();
+        tree.insert(&["login_form".to_string()], "LoginForm".to_string());
+        tree.insert(&["forms".to_string(), "search".to_string()], "Search".to_string());
+
+        let rendered = tree.render();
+        assert!(rendered.starts_with(MOD_HEADER));
+        assert!(rendered.contains("pub mod forms;\npub mod login_form;\n"));
+        assert!(rendered.contains("pub use login_form::LoginForm;"));
+        assert!(!rendered.contains("pub use forms"));
+        assert!(tree.dirs["forms"].render().contains("pub use search::Search;"));
+    }
+
+    #[test]
+    fn test_module_tree_remove_prunes_empty_dirs() {
+        let mut tree = ModuleTree::default();
+        let path = vec!["forms".to_string(), "search".to_string()];
+        tree.insert(&path, "Search".to_string());
+        tree.remove(&path);
+
+        assert!(tree.is_empty());
+        assert!(!tree.render().contains("pub mod forms;"));
+    }
+
+    #[test]
+    fn test_compile_directory() {
+        let output = temp_output("compile-directory");
+        run(&[testdata("basic")], Some(&output), &UtamConfig::default(), false).unwrap();
+
+        assert!(output.join("simple_element.rs").is_file());
+        assert!(output.join("clickable_button.rs").is_file());
+        let mod_rs = fs::read_to_string(output.join("mod.rs")).unwrap();
+        assert!(mod_rs.contains("pub mod editable_input;"));
+        assert!(mod_rs.contains("pub use simple_element::SimpleElement;"));
+
+        let _ = fs::remove_dir_all(&output);
+    }
+
+    fn project_config() -> UtamConfig {
+        let mut packages = BTreeMap::new();
+        packages.insert("my-app".to_string(), "crate::my_app".to_string());
+        UtamConfig { packages, ..Default::default() }
+    }
+
+    /// Copy page objects from a testdata directory into a temporary input
+    /// directory, under the `my-app` package
+    fn package_input(name: &str, dir: &str, page_objects: &[&str]) -> PathBuf {
+        let input = temp_output(name);
+        let package = input.join("my-app");
+        fs::create_dir_all(&package).unwrap();
+        for page_object in page_objects {
+            let file = format!("{}.utam.json", page_object);
+            fs::copy(testdata(dir).join(&file), package.join(&file)).unwrap();
+        }
+        input
+    }
+
+    #[test]
+    fn test_compile_resolves_project_components() {
+        let output = temp_output("compile-project");
+        run(&[testdata("project")], Some(&output), &project_config(), false).unwrap();
+
+        let navigation = output.join("my_app").join("forms").join("navigation.rs");
+        let navigation = fs::read_to_string(navigation).unwrap();
+        assert!(navigation.contains("crate::my_app::tree::Tree::from_element"));
+
+        let _ = fs::remove_dir_all(&output);
+    }
+
+    #[test]
+    fn test_compile_without_packages_uses_struct_name() {
+        let output = temp_output("compile-project-unmapped");
+        run(&[testdata("project")], Some(&output), &UtamConfig::default(), false).unwrap();
+
+        let navigation = output.join("my_app").join("forms").join("navigation.rs");
+        let navigation = fs::read_to_string(navigation).unwrap();
+        assert!(navigation.contains("Tree::from_element"));
+        assert!(!navigation.contains("crate::my_app::"));
+
+        let _ = fs::remove_dir_all(&output);
+    }
+
+    #[test]
+    fn test_compile_checks_chains_against_project_components() {
+        let input = package_input("compile-chain-input", "chain", &["dialog", "checkout"]);
+        let output = temp_output("compile-chain");
+        run(std::slice::from_ref(&input), Some(&output), &project_config(), false).unwrap();
+
+        let checkout = fs::read_to_string(output.join("my_app").join("checkout.rs")).unwrap();
+        assert!(checkout.contains("previous.get_ok_button()"));
+
+        let _ = fs::remove_dir_all(&input);
+
+        let _ = fs::remove_dir_all(&output);
+    }
+
+    #[test]
+    fn test_compile_reports_unresolved_component() {
+        let input =
+            package_input("compile-unresolved-input", "project/my-app/forms", &["navigation"]);
+        let output = temp_output("compile-unresolved");
+        let result = run(std::slice::from_ref(&input), Some(&output), &project_config(), false);
+
+        assert!(matches!(result, Err(CliError::ValidationFailed { failed: 1, total: 1 })));
+        assert!(!output.join("my_app").join("navigation.rs").exists());
+
+        let _ = fs::remove_dir_all(&input);
+        let _ = fs::remove_dir_all(&output);
+    }
+
+    #[test]
+    fn test_compile_reports_validation_failure() {
+        let output = temp_output("compile-invalid");
+        let input = testdata("invalid/missing-selector.utam.json");
+        let result = run(&[input], Some(&output), &UtamConfig::default(), false);
+
+        assert!(matches!(result, Err(CliError::ValidationFailed { failed: 1, total: 1 })));
+        assert!(!output.join("missing_selector.rs").exists());
+
+        let _ = fs::remove_dir_all(&output);
+    }
+}
@@ -0,0 +1,119 @@
+//! `utam init` - scaffold a UTAM project
+//!
+//! Writes a starter `utam.config.json`, a sample page object, a tokio test
+//! that loads it, and compiles the sample so the generated
+//! `src/pageobjects/` module tree exists straight away.
+
+use std::path::{Path, PathBuf};
+
+use console::style;
+
+use crate::commands::compile::{output_dir, write_file, Session};
+use crate::commands::{failure_result, input_files, resolve_inputs};
+use crate::config::{UtamConfig, DEFAULT_CONFIG_FILE};
+use crate::error::{CliError, CliResult};
+
+/// Sample page object compiled by `utam init`
+const SAMPLE_PAGE_OBJECT: &str = "pageobjects/login.utam.json";
+
+/// Starter files written by `utam init`, relative to the project root
+const TEMPLATES: &[(&str, &str)] = &[
+    (DEFAULT_CONFIG_FILE, include_str!("../templates/utam.config.json")),
+    (SAMPLE_PAGE_OBJECT, include_str!("../templates/login.utam.json")),
+    ("tests/login.rs", include_str!("../templates/login_test.rs.tmpl")),
+];
+
+/// Files generated from the sample page object, relative to the project root
+const GENERATED: &[&str] = &["src/pageobjects/mod.rs", "src/pageobjects/login.rs"];
+
+/// Run `utam init` in the current directory
+///
+/// # Errors
+///
+/// * `CliError::AlreadyExists` - When a file would be overwritten without `--force`
+pub fn run(force: bool, verbose: bool) -> CliResult<()> {
+    init(Path::new("."), force, verbose)
+}
+
+/// Scaffold a project rooted at `root`
+fn init(root: &Path, force: bool, verbose: bool) -> CliResult<()> {
+    if !force {
+        let existing: Vec<PathBuf> = TEMPLATES
+            .iter()
+            .map(|(path, _)| *path)
+            .chain(GENERATED.iter().copied())
+            .map(|path| root.join(path))
+            .filter(|path| path.exists())
+            .collect();
+        if !existing.is_empty() {
+            return Err(CliError::AlreadyExists { paths: existing });
+        }
+    }
+
+    for (path, contents) in TEMPLATES {
+        let path = root.join(path);
+        write_file(&path, contents)?;
+        println!("{} {}", style("created").green(), path.display());
+    }
+
+    let config = UtamConfig::load(&root.join(DEFAULT_CONFIG_FILE))?;
+    let files = input_files(&resolve_inputs(&[], &config)?, &config)?;
+    let mut session = Session::new(output_dir(None, &config), config.codegen_config(), verbose)?;
+    let failures = session.compile(&files);
+    session.write_modules()?;
+    session.print_summary(&failures, files.len());
+    failure_result(failures.io, failures.invalid, files.len())?;
+
+    println!();
+    println!("Next steps:");
+    println!("  1. Add utam-core, thirtyfour, tokio and async-trait to your Cargo.toml");
+    println!("  2. Edit {} to describe your page", SAMPLE_PAGE_OBJECT);
+    println!("  3. Run `utam compile` (or `utam compile --watch`) after each change");
+    println!("  4. Run `cargo test -- --ignored` with a WebDriver server running");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("utam-cli-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_init_scaffolds_project() {
+        let root = temp_root("init");
+        init(&root, false, false).unwrap();
+
+        for (path, _) in TEMPLATES {
+            assert!(root.join(path).is_file(), "missing {}", path);
+        }
+        for path in GENERATED {
+            assert!(root.join(path).is_file(), "missing {}", path);
+        }
+        let mod_rs = fs::read_to_string(root.join("src/pageobjects/mod.rs")).unwrap();
+        assert!(mod_rs.contains("pub use login::Login;"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_init_refuses_to_overwrite() {
+        let root = temp_root("init-existing");
+        fs::write(root.join(DEFAULT_CONFIG_FILE), "{}").unwrap();
+
+        let result = init(&root, false, false);
+        assert!(matches!(result, Err(CliError::AlreadyExists { ref paths }) if paths.len() == 1));
+        assert_eq!(fs::read_to_string(root.join(DEFAULT_CONFIG_FILE)).unwrap(), "{}");
+
+        init(&root, true, false).unwrap();
+        assert_ne!(fs::read_to_string(root.join(DEFAULT_CONFIG_FILE)).unwrap(), "{}");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
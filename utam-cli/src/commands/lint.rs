@@ -0,0 +1,118 @@
+//! `utam lint` - report style and maintainability problems in page objects
+//!
+//! Runs the rules in [`crate::lint`] at the severities configured under
+//! `lint.rules` in `utam.config.json`. Findings are printed to the terminal
+//! and, with `--sarif`, also written as a SARIF log for code scanning.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use console::style;
+
+use crate::commands::compile::write_file;
+use crate::commands::validate::{parse_page_object, INVALID_JSON, STRUCTURE};
+use crate::commands::{failure_result, input_files, report_error, resolve_inputs};
+use crate::config::UtamConfig;
+use crate::diagnostics::{render, OutputFormat, Summary};
+use crate::error::{CliError, CliResult};
+use crate::lint::Linter;
+use crate::sarif::SarifReport;
+
+/// Run `utam lint`
+///
+/// Warnings are reported without failing; any error-level finding fails
+/// the command.
+///
+/// # Errors
+///
+/// * `CliError::UnknownLintRule` - When the config names a rule that does not exist
+/// * `CliError::ValidationFailed` - When any file has an error-level finding
+/// * `CliError::IoFailed` - When any file cannot be read
+pub fn run(inputs: &[PathBuf], sarif: Option<&Path>, config: &UtamConfig) -> CliResult<()> {
+    let linter = Linter::new(&config.lint)?;
+    let files = input_files(&resolve_inputs(inputs, config)?, config)?;
+
+    let mut diagnostics = Vec::new();
+    let mut io_failed = 0;
+    for file in &files {
+        let source = match fs::read_to_string(&file.path) {
+            Ok(source) => source,
+            Err(source) => {
+                report_error(&CliError::Io { path: file.path.clone(), source });
+                io_failed += 1;
+                continue;
+            }
+        };
+        match parse_page_object(&file.path, &source) {
+            Ok(ast) => diagnostics.extend(linter.lint(&file.path, &source, &ast)),
+            Err(diagnostic) => diagnostics.push(diagnostic),
+        }
+    }
+
+    print!("{}", render(OutputFormat::Text, &diagnostics, &[], files.len()));
+
+    if let Some(path) = sarif {
+        let mut rules = vec![INVALID_JSON, STRUCTURE];
+        rules.extend(linter.rules());
+        write_file(path, &SarifReport::new(&rules, &diagnostics).to_json())?;
+        println!("{} {}", style("Wrote").green().bold(), path.display());
+    }
+
+    let summary = Summary::new(&diagnostics, files.len());
+    failure_result(io_failed, summary.failed, files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    use crate::config::{LintConfig, Severity};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("utam-cli-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    const PAGE_OBJECT: &str = r#"{
+  "root": true,
+  "selector": { "css": "div" },
+  "elements": [{ "name": "submit", "public": true, "selector": { "css": "button.submit" } }]
+}"#;
+
+    #[test]
+    fn test_warnings_pass_and_write_sarif() {
+        let dir = temp_dir("lint-sarif");
+        let input = dir.join("page.utam.json");
+        fs::write(&input, PAGE_OBJECT).unwrap();
+        let sarif = dir.join("reports/lint.sarif");
+
+        run(&[input], Some(&sarif), &UtamConfig::default()).unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&sarif).unwrap()).unwrap();
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r["level"] == "warning"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_error_severity_fails() {
+        let dir = temp_dir("lint-error");
+        let input = dir.join("page.utam.json");
+        fs::write(&input, PAGE_OBJECT).unwrap();
+
+        let mut rules = BTreeMap::new();
+        rules.insert("generic-selector".to_string(), Severity::Error);
+        let config = UtamConfig { lint: LintConfig { rules }, ..UtamConfig::default() };
+
+        let result = run(&[input], None, &config);
+        assert!(matches!(result, Err(CliError::ValidationFailed { failed: 1, total: 1 })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+//! Subcommand implementations for the UTAM CLI
+//!
+//! Each subcommand lives in its own module and exposes a `run` function
+//! returning [`CliResult`](crate::error::CliResult).
+
+pub mod compile;
+pub mod init;
+pub mod lint;
+pub mod validate;
+pub mod watch;
+
+use std::path::{Path, PathBuf};
+
+use console::style;
+use utam_compiler::{CompilerError, ErrorReporter};
+
+use crate::config::UtamConfig;
+use crate::error::{CliError, CliResult};
+use crate::files::{collect_input_files, InputFile};
+
+/// Inputs for a command: the command line inputs, or the configured directories
+///
+/// # Errors
+///
+/// * `CliError::NoInputs` - When neither provides any input
+pub(crate) fn resolve_inputs(args: &[PathBuf], config: &UtamConfig) -> CliResult<Vec<PathBuf>> {
+    let inputs = config.inputs(args);
+    if inputs.is_empty() {
+        return Err(CliError::NoInputs);
+    }
+    Ok(inputs)
+}
+
+/// Expand inputs into UTAM files, applying the config's include and exclude patterns
+pub(crate) fn input_files(inputs: &[PathBuf], config: &UtamConfig) -> CliResult<Vec<InputFile>> {
+    let mut files = collect_input_files(inputs)?;
+    files.retain(|file| config.selects(file));
+    Ok(files)
+}
+
+/// Print a compiler diagnostic for a file with source snippets
+pub(crate) fn report_compiler_error(path: &Path, source: &str, error: &CompilerError) {
+    ErrorReporter::new(source.to_string(), path.display().to_string()).report(error);
+}
+
+/// Print a CLI error to stderr
+pub(crate) fn report_error(error: &CliError) {
+    eprintln!("{} {}", style("error:").red().bold(), error);
+}
+
+/// Turn per-file failure counts into the command result
+///
+/// I/O failures take precedence over validation failures so that a broken
+/// environment is never reported as a broken page object.
+pub(crate) fn failure_result(io_failed: usize, invalid: usize, total: usize) -> CliResult<()> {
+    if io_failed > 0 {
+        Err(CliError::IoFailed { failed: io_failed, total })
+    } else if invalid > 0 {
+        Err(CliError::ValidationFailed { failed: invalid, total })
+    } else {
+        Ok(())
+    }
+}
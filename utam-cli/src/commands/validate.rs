@@ -0,0 +1,269 @@
+//! `utam validate` - check page objects without generating code
+//!
+//! Runs the schema validator and `PageObjectAst::validate` over every
+//! input file, collecting all problems with their positions instead of
+//! stopping at the first one.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use utam_compiler::{AstCheck, CompilerError, PageObjectAst, SchemaValidator};
+
+use crate::commands::{failure_result, input_files, report_error, resolve_inputs};
+use crate::config::UtamConfig;
+use crate::diagnostics::{render, Diagnostic, OutputFormat, Rule, Summary};
+use crate::error::{CliError, CliResult};
+
+/// The file is not well-formed JSON
+pub(crate) const INVALID_JSON: Rule =
+    Rule { id: "invalid-json", description: "File is well-formed JSON" };
+
+/// The file does not match the page object schema
+const SCHEMA: Rule =
+    Rule { id: "schema", description: "Page object matches the UTAM page object JSON schema" };
+
+/// The file cannot be read into the page object model
+pub(crate) const STRUCTURE: Rule =
+    Rule { id: "structure", description: "Page object can be read into the UTAM model" };
+
+/// An element fails `ElementAst::validate`
+const ELEMENT: Rule = Rule {
+    id: "element",
+    description: "Element names are valid Rust identifiers, frames do not use returnAll \
+                  and only lists have filters",
+};
+
+/// Two elements share a name, in any scope
+const DUPLICATE_ELEMENT: Rule =
+    Rule { id: "duplicate-element", description: "Element names are unique across all scopes" };
+
+/// A selector's placeholders do not match its arguments
+const SELECTOR_PARAMETERS: Rule = Rule {
+    id: "selector-parameters",
+    description: "Selector %s/%d placeholders match the selector arguments",
+};
+
+/// A selector strategy is not available on the page object's platform
+const PLATFORM_SELECTOR: Rule = Rule {
+    id: "platform-selector",
+    description: "Selectors use css on web and accessid, classchain or uiautomator on native",
+};
+
+/// Every rule `utam validate` can report
+pub const RULES: &[Rule] = &[
+    INVALID_JSON,
+    SCHEMA,
+    STRUCTURE,
+    ELEMENT,
+    DUPLICATE_ELEMENT,
+    SELECTOR_PARAMETERS,
+    PLATFORM_SELECTOR,
+];
+
+/// Run `utam validate`
+///
+/// Diagnostics are written to stdout in the requested format.
+///
+/// # Errors
+///
+/// * `CliError::ValidationFailed` - When any file has an error diagnostic
+/// * `CliError::IoFailed` - When any file cannot be read
+pub fn run(inputs: &[PathBuf], format: OutputFormat, config: &UtamConfig) -> CliResult<()> {
+    let files = input_files(&resolve_inputs(inputs, config)?, config)?;
+    let validator = SchemaValidator::new()?;
+
+    let mut diagnostics = Vec::new();
+    let mut io_failed = 0;
+    for file in &files {
+        match fs::read_to_string(&file.path) {
+            Ok(source) => diagnostics.extend(validate_source(&validator, &file.path, &source)),
+            Err(source) => {
+                report_error(&CliError::Io { path: file.path.clone(), source });
+                io_failed += 1;
+            }
+        }
+    }
+
+    print!("{}", render(format, &diagnostics, RULES, files.len()));
+    let summary = Summary::new(&diagnostics, files.len());
+    failure_result(io_failed, summary.failed, files.len())
+}
+
+/// Validate one page object, returning every problem found
+///
+/// JSON syntax, schema and model errors stop further checks, since the
+/// later checks need a parsed page object.
+pub(crate) fn validate_source(
+    validator: &SchemaValidator,
+    path: &Path,
+    source: &str,
+) -> Vec<Diagnostic> {
+    let json: Value = match serde_json::from_str(source) {
+        Ok(json) => json,
+        Err(error) => {
+            return vec![Diagnostic::new(
+                path,
+                error.line(),
+                error.column(),
+                &INVALID_JSON,
+                error.to_string(),
+            )]
+        }
+    };
+
+    match validator.validate(&json) {
+        Ok(()) => {}
+        Err(CompilerError::SchemaValidation(errors)) => {
+            return errors
+                .iter()
+                .map(|e| Diagnostic::at_pointer(path, source, &e.path, &SCHEMA, &e.message))
+                .collect();
+        }
+        Err(error) => return vec![Diagnostic::new(path, 1, 1, &SCHEMA, error.to_string())],
+    }
+
+    let ast = match parse_page_object(path, source) {
+        Ok(ast) => ast,
+        Err(diagnostic) => return vec![diagnostic],
+    };
+
+    let Err(errors) = ast.validate() else {
+        return Vec::new();
+    };
+    errors
+        .iter()
+        .map(|error| {
+            let rule = match error.check {
+                AstCheck::Element => &ELEMENT,
+                AstCheck::DuplicateName => &DUPLICATE_ELEMENT,
+                AstCheck::SelectorParameters => &SELECTOR_PARAMETERS,
+                AstCheck::PlatformSelector => &PLATFORM_SELECTOR,
+            };
+            Diagnostic::at_pointer(path, source, &error.path, rule, &error.message)
+        })
+        .collect()
+}
+
+/// Read a page object into the model, reporting failure as a diagnostic
+///
+/// Syntax errors are reported as [`INVALID_JSON`] and model errors as
+/// [`STRUCTURE`], both at the position serde stopped.
+pub(crate) fn parse_page_object(path: &Path, source: &str) -> Result<PageObjectAst, Diagnostic> {
+    serde_json::from_str(source).map_err(|error| {
+        let rule = if error.is_data() { &STRUCTURE } else { &INVALID_JSON };
+        Diagnostic::new(path, error.line(), error.column(), rule, error.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(source: &str) -> Vec<Diagnostic> {
+        let validator = SchemaValidator::new().unwrap();
+        validate_source(&validator, Path::new("test.utam.json"), source)
+    }
+
+    fn testdata(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("testdata").join(path)
+    }
+
+    #[test]
+    fn test_valid_page_object() {
+        let source = fs::read_to_string(testdata("basic/clickable-button.utam.json")).unwrap();
+        assert_eq!(validate(&source), vec![]);
+    }
+
+    #[test]
+    fn test_invalid_json_position() {
+        let diagnostics = validate("{\n  \"root\": true,\n  oops\n}");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule_id, INVALID_JSON.id);
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn test_schema_error() {
+        let source = fs::read_to_string(testdata("invalid/missing-selector.utam.json")).unwrap();
+        let diagnostics = validate(&source);
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics.iter().all(|d| d.rule_id == SCHEMA.id));
+    }
+
+    #[test]
+    fn test_element_and_duplicate_checks() {
+        let source = r#"{
+  "root": true,
+  "selector": { "css": "app" },
+  "elements": [
+    { "name": "button", "selector": { "css": "b" } },
+    { "name": "button", "selector": { "css": "c" } },
+    { "name": "box", "selector": { "css": "d" }, "elements": [
+      { "name": "frame", "type": "frame", "selector": { "css": "iframe", "returnAll": true } }
+    ]}
+  ]
+}"#;
+        let diagnostics = validate(source);
+
+        let duplicate = diagnostics.iter().find(|d| d.rule_id == DUPLICATE_ELEMENT.id).unwrap();
+        assert_eq!((duplicate.line, duplicate.column), (6, 15));
+
+        let frame = diagnostics.iter().find(|d| d.rule_id == ELEMENT.id).unwrap();
+        assert_eq!(frame.line, 8);
+        assert!(frame.message.contains("returnAll"));
+    }
+
+    #[test]
+    fn test_duplicate_names_across_scopes() {
+        let source =
+            fs::read_to_string(testdata("invalid/duplicate-nested-names.utam.json")).unwrap();
+        let diagnostics = validate(&source);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|d| d.rule_id == DUPLICATE_ELEMENT.id));
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (17, 19));
+        assert_eq!((diagnostics[1].line, diagnostics[1].column), (26, 21));
+    }
+
+    #[test]
+    fn test_selector_parameter_mismatch() {
+        let source = r#"{
+  "root": true,
+  "selector": { "css": "app" },
+  "elements": [
+    { "name": "item", "selector": { "css": "li:nth-child(%d)", "args": [
+      { "name": "index", "type": "number" },
+      { "name": "extra", "type": "string" }
+    ]}}
+  ]
+}"#;
+        let diagnostics = validate(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule_id, SELECTOR_PARAMETERS.id);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (5, 35));
+    }
+
+    #[test]
+    fn test_platform_selector() {
+        let source = fs::read_to_string(testdata("mobile/native-selectors.utam.json")).unwrap();
+        assert_eq!(validate(&source), vec![]);
+
+        let source =
+            fs::read_to_string(testdata("invalid/unsupported-selector.utam.json")).unwrap();
+        let diagnostics = validate(&source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule_id, PLATFORM_SELECTOR.id);
+        assert!(diagnostics[0].message.contains("accessid"));
+    }
+
+    #[test]
+    fn test_run_reports_validation_failure() {
+        let result = run(
+            &[testdata("invalid/missing-selector.utam.json")],
+            OutputFormat::Json,
+            &UtamConfig::default(),
+        );
+        assert!(matches!(result, Err(CliError::ValidationFailed { failed: 1, total: 1 })));
+    }
+}
@@ -0,0 +1,374 @@
+//! `utam compile --watch` - recompile page objects as they change
+//!
+//! Watches the input paths with `notify`, batches bursts of file system
+//! events (editors often write a file several times per save), and
+//! recompiles only the changed `.utam.json` files plus the page objects
+//! that use them as custom component types. Errors are reported inline
+//! and never stop the watcher.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use console::style;
+use notify::{Event, RecursiveMode, Watcher};
+use utam_compiler::{ElementAst, ElementKind, PageObjectAst};
+
+use crate::commands::compile::{output_dir, Session};
+use crate::commands::{input_files, report_error, resolve_inputs};
+use crate::config::UtamConfig;
+use crate::error::{CliError, CliResult};
+use crate::files::{check_output_paths, InputFile, UTAM_EXTENSION};
+
+/// Quiet period that ends a burst of file system events
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Run `utam compile --watch`
+///
+/// Performs a full compilation, then recompiles on every change until the
+/// process is interrupted.
+///
+/// # Errors
+///
+/// * `CliError::Watch` - When the file system watcher cannot be started
+/// * `CliError::OutputCollision` - When two files would be compiled to the same output
+pub fn run(
+    inputs: &[PathBuf],
+    output: Option<&Path>,
+    config: &UtamConfig,
+    verbose: bool,
+) -> CliResult<()> {
+    let inputs = resolve_inputs(inputs, config)?;
+    let mut session = Session::new(output_dir(output, config), config.codegen_config(), verbose)?;
+    let mut state = WatchState::default();
+
+    let files = input_files(&inputs, config)?;
+    check_output_paths(&files)?;
+    state.refresh(&files);
+    session.set_symbols(config.symbol_table(&files));
+    let failures = session.compile(&files);
+    session.write_modules()?;
+    session.print_summary(&failures, files.len());
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for (root, mode) in watch_roots(&inputs) {
+        watcher.watch(&root, mode)?;
+    }
+
+    println!(
+        "{} for changes (output: {}). Press Ctrl+C to stop.",
+        style("Watching").cyan().bold(),
+        session.output_dir().display()
+    );
+
+    while let Ok(first) = rx.recv() {
+        let mut events = vec![first];
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            events.push(event);
+        }
+
+        let changed = changed_utam_files(events);
+        if changed.is_empty() {
+            continue;
+        }
+
+        if let Err(error) = recompile(&inputs, config, &changed, &mut session, &mut state) {
+            report_error(&error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Recompile a batch of changed files and their dependents
+fn recompile(
+    inputs: &[PathBuf],
+    config: &UtamConfig,
+    changed: &BTreeSet<PathBuf>,
+    session: &mut Session,
+    state: &mut WatchState,
+) -> CliResult<()> {
+    let files = input_files(inputs, config)?;
+    check_output_paths(&files)?;
+    let removed = state.refresh(&files);
+    session.set_symbols(config.symbol_table(&files));
+    for file in &removed {
+        println!("{} {}", style("removed").yellow(), file.path.display());
+        session.remove(file)?;
+    }
+
+    let mut targets: BTreeSet<PathBuf> =
+        changed.iter().filter(|p| state.files.contains_key(*p)).cloned().collect();
+    let names: BTreeSet<String> = targets
+        .iter()
+        .filter_map(|p| state.files.get(p))
+        .chain(&removed)
+        .map(InputFile::page_object_name)
+        .collect();
+    targets.extend(state.graph.dependents(&names, &state.files));
+
+    let batch: Vec<InputFile> =
+        targets.iter().filter_map(|p| state.files.get(p)).cloned().collect();
+    for file in &batch {
+        println!("{} {}", style("changed").cyan(), file.path.display());
+    }
+
+    let failures = session.compile(&batch);
+    session.write_modules()?;
+    session.print_summary(&failures, batch.len());
+    Ok(())
+}
+
+/// Files known to the watcher and the references between them
+#[derive(Debug, Default)]
+struct WatchState {
+    /// Current input files keyed by canonical path
+    files: BTreeMap<PathBuf, InputFile>,
+    /// Custom component references between the input files
+    graph: DependencyGraph,
+}
+
+impl WatchState {
+    /// Replace the known file set, re-reading references of every file
+    ///
+    /// Returns the files that disappeared since the last refresh.
+    fn refresh(&mut self, files: &[InputFile]) -> Vec<InputFile> {
+        let current: BTreeMap<PathBuf, InputFile> =
+            files.iter().map(|f| (canonical(&f.path), f.clone())).collect();
+
+        let removed: Vec<InputFile> = self
+            .files
+            .iter()
+            .filter(|(path, _)| !current.contains_key(*path))
+            .map(|(_, file)| file.clone())
+            .collect();
+        for path in self.files.keys().filter(|p| !current.contains_key(*p)) {
+            self.graph.remove(path);
+        }
+
+        for (path, file) in &current {
+            match fs::read_to_string(&file.path) {
+                Ok(source) => self.graph.update(path, &source),
+                Err(_) => self.graph.remove(path),
+            }
+        }
+
+        self.files = current;
+        removed
+    }
+}
+
+/// Tracks which page objects use which others as custom component types
+#[derive(Debug, Default)]
+pub(crate) struct DependencyGraph {
+    /// Component names referenced by each page object file
+    references: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+impl DependencyGraph {
+    /// Record the references of a page object from its JSON source
+    ///
+    /// Sources that do not parse are recorded with no references; the
+    /// compiler reports the parse error when the file is compiled.
+    pub(crate) fn update(&mut self, path: &Path, source: &str) {
+        let references = serde_json::from_str::<PageObjectAst>(source)
+            .map(|ast| component_references(&ast))
+            .unwrap_or_default();
+        self.references.insert(path.to_path_buf(), references);
+    }
+
+    /// Forget a page object
+    pub(crate) fn remove(&mut self, path: &Path) {
+        self.references.remove(path);
+    }
+
+    /// Files that directly or transitively reference any of `names`
+    pub(crate) fn dependents(
+        &self,
+        names: &BTreeSet<String>,
+        files: &BTreeMap<PathBuf, InputFile>,
+    ) -> BTreeSet<PathBuf> {
+        let mut result = BTreeSet::new();
+        let mut pending: Vec<String> = names.iter().cloned().collect();
+        let mut seen: BTreeSet<String> = names.clone();
+
+        while let Some(name) = pending.pop() {
+            for (path, references) in &self.references {
+                if references.contains(&name) && result.insert(path.clone()) {
+                    if let Some(file) = files.get(path) {
+                        let dependent = file.page_object_name();
+                        if seen.insert(dependent.clone()) {
+                            pending.push(dependent);
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Names of all page objects used as custom component types in `ast`
+fn component_references(ast: &PageObjectAst) -> BTreeSet<String> {
+    fn collect(elements: &[ElementAst], out: &mut BTreeSet<String>) {
+        for element in elements {
+            if let ElementKind::Custom(component) = element.element_kind() {
+                out.insert(component.name);
+            }
+            if let Some(shadow) = &element.shadow {
+                collect(&shadow.elements, out);
+            }
+            collect(&element.elements, out);
+        }
+    }
+
+    let mut references = BTreeSet::new();
+    collect(&ast.elements, &mut references);
+    if let Some(shadow) = &ast.shadow {
+        collect(&shadow.elements, &mut references);
+    }
+    references
+}
+
+/// Canonical `.utam.json` paths touched by a batch of events
+fn changed_utam_files(events: Vec<notify::Result<Event>>) -> BTreeSet<PathBuf> {
+    let mut changed = BTreeSet::new();
+    for event in events {
+        match event {
+            Ok(event) if !event.kind.is_access() => {
+                changed.extend(
+                    event
+                        .paths
+                        .into_iter()
+                        .filter(|p| p.to_string_lossy().ends_with(UTAM_EXTENSION))
+                        .map(|p| canonical(&p)),
+                );
+            }
+            Ok(_) => {}
+            Err(error) => report_error(&CliError::Watch(error)),
+        }
+    }
+    changed
+}
+
+/// Directories to watch for the given inputs
+///
+/// Directories are watched recursively. Single files are watched through
+/// their parent directory so editors that save by renaming are picked up.
+/// Glob patterns are watched from their last literal directory.
+fn watch_roots(inputs: &[PathBuf]) -> BTreeSet<(PathBuf, RecursiveMode)> {
+    inputs
+        .iter()
+        .map(|input| {
+            if input.is_dir() {
+                (input.clone(), RecursiveMode::Recursive)
+            } else if input.is_file() {
+                (parent_dir(input), RecursiveMode::NonRecursive)
+            } else {
+                let literal: PathBuf =
+                    input.components().take_while(|c| !is_glob_component(c)).collect();
+                let root =
+                    if literal.as_os_str().is_empty() { PathBuf::from(".") } else { literal };
+                (root, RecursiveMode::Recursive)
+            }
+        })
+        .collect()
+}
+
+/// Directory containing `path`, or the current directory for bare file names
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Returns true if a path component contains glob metacharacters
+fn is_glob_component(component: &Component) -> bool {
+    component.as_os_str().to_string_lossy().contains(['*', '?', '[', '{'])
+}
+
+/// Canonicalize a path, falling back to the path itself if it no longer exists
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(name: &str) -> InputFile {
+        InputFile::new(PathBuf::from(format!("{}.utam.json", name)), None)
+    }
+
+    fn graph_with(entries: &[(&str, &str)]) -> (DependencyGraph, BTreeMap<PathBuf, InputFile>) {
+        let mut graph = DependencyGraph::default();
+        let mut files = BTreeMap::new();
+        for (name, source) in entries {
+            let file = input(name);
+            graph.update(&file.path, source);
+            files.insert(file.path.clone(), file);
+        }
+        (graph, files)
+    }
+
+    #[test]
+    fn test_component_references_include_nested_and_shadow() {
+        let json = r#"{
+            "root": true,
+            "selector": { "css": "app" },
+            "shadow": { "elements": [
+                { "name": "tree", "type": "utam-lightning/pageObjects/tree", "selector": { "css": "tree" } }
+            ]},
+            "elements": [
+                { "name": "outer", "selector": { "css": ".outer" }, "elements": [
+                    { "name": "button", "type": "pkg/pageObjects/button", "selector": { "css": "b" } }
+                ]}
+            ]
+        }"#;
+        let ast: PageObjectAst = serde_json::from_str(json).unwrap();
+        let references = component_references(&ast);
+        assert!(references.contains("tree"));
+        assert!(references.contains("button"));
+        assert_eq!(references.len(), 2);
+    }
+
+    #[test]
+    fn test_dependents_are_transitive() {
+        let (graph, files) = graph_with(&[
+            ("button", r#"{ "selector": { "css": "b" } }"#),
+            (
+                "form",
+                r#"{ "elements": [{ "name": "b", "type": "pkg/pageObjects/button", "selector": { "css": "b" } }] }"#,
+            ),
+            (
+                "page",
+                r#"{ "root": true, "selector": { "css": "p" }, "elements": [{ "name": "f", "type": "pkg/pageObjects/form", "selector": { "css": "f" } }] }"#,
+            ),
+        ]);
+
+        let names = BTreeSet::from(["button".to_string()]);
+        let dependents = graph.dependents(&names, &files);
+        assert!(dependents.contains(&PathBuf::from("form.utam.json")));
+        assert!(dependents.contains(&PathBuf::from("page.utam.json")));
+        assert!(!dependents.contains(&PathBuf::from("button.utam.json")));
+    }
+
+    #[test]
+    fn test_unparseable_source_has_no_references() {
+        let (graph, files) = graph_with(&[("broken", "{ not json")]);
+        let names = BTreeSet::from(["anything".to_string()]);
+        assert!(graph.dependents(&names, &files).is_empty());
+    }
+
+    #[test]
+    fn test_watch_roots_for_glob_pattern() {
+        let roots = watch_roots(&[PathBuf::from("does-not-exist/pageobjects/**/*.utam.json")]);
+        assert!(roots
+            .contains(&(PathBuf::from("does-not-exist/pageobjects"), RecursiveMode::Recursive)));
+    }
+}
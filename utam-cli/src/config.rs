@@ -0,0 +1,428 @@
+//! Project configuration loaded from `utam.config.json`
+//!
+//! The config file supplies defaults for arguments that would otherwise be
+//! repeated on every invocation: where page objects live, where generated
+//! code goes, how custom component packages map to Rust modules, lint rule
+//! severities and wait timeouts. Command line arguments always take
+//! precedence over the config file.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+use utam_compiler::{CodeGenConfig, PageObjectAst, SymbolTable};
+
+use crate::files::InputFile;
+
+/// Config file read when `--config` is not given
+pub const DEFAULT_CONFIG_FILE: &str = "utam.config.json";
+
+/// Default `include` pattern: every UTAM file below an input directory
+const DEFAULT_INCLUDE: &str = "**/*.utam.json";
+
+/// Errors loading a config file
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    /// The config file could not be read
+    #[error("Failed to read config '{}': {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The config file is not valid JSON
+    #[error("Invalid JSON in config '{}': {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The config file does not match the config schema
+    #[error(
+        "Invalid config '{}':\n{}",
+        path.display(),
+        errors.iter().map(|e| format!("  - {}", e)).collect::<Vec<_>>().join("\n")
+    )]
+    Schema { path: PathBuf, errors: Vec<String> },
+
+    /// An `include` or `exclude` entry is not a valid glob pattern
+    #[error("Invalid {field} pattern '{pattern}' in config: {source}")]
+    Pattern {
+        field: &'static str,
+        pattern: String,
+        #[source]
+        source: glob::PatternError,
+    },
+}
+
+/// Typed contents of `utam.config.json`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtamConfig {
+    /// Directories searched for page objects when no inputs are given
+    #[serde(default)]
+    pub input_directories: Vec<PathBuf>,
+    /// Output directory when `--output` is not given
+    #[serde(default)]
+    pub output_directory: Option<PathBuf>,
+    /// Patterns a file found in a directory must match to be processed
+    #[serde(default = "default_include")]
+    pub include: Vec<String>,
+    /// Patterns of files found in a directory to skip
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Rust module paths for custom component packages, keyed by package name
+    ///
+    /// Input files under a directory named after a package are that
+    /// package's page objects, and are resolved as custom components.
+    #[serde(default)]
+    pub packages: BTreeMap<String, String>,
+    /// Wait defaults for generated page objects
+    #[serde(default)]
+    pub timeouts: TimeoutConfig,
+    /// Lint rule configuration
+    #[serde(default)]
+    pub lint: LintConfig,
+}
+
+/// Wait defaults for generated page objects, in milliseconds
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeoutConfig {
+    /// Timeout for locating the root element when loading a page object
+    pub wait: Option<u64>,
+    /// Interval between polls in generated waits
+    pub poll_interval: Option<u64>,
+}
+
+/// Lint rule configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct LintConfig {
+    /// Severity overrides keyed by rule ID
+    #[serde(default)]
+    pub rules: BTreeMap<String, Severity>,
+}
+
+/// Severity of a lint rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The rule is disabled
+    Off,
+    /// Violations are reported but do not fail the command
+    Warn,
+    /// Violations fail the command
+    Error,
+}
+
+fn default_include() -> Vec<String> {
+    vec![DEFAULT_INCLUDE.to_string()]
+}
+
+impl Default for UtamConfig {
+    fn default() -> Self {
+        Self {
+            input_directories: Vec::new(),
+            output_directory: None,
+            include: default_include(),
+            exclude: Vec::new(),
+            packages: BTreeMap::new(),
+            timeouts: TimeoutConfig::default(),
+            lint: LintConfig::default(),
+        }
+    }
+}
+
+impl UtamConfig {
+    /// Load the project configuration
+    ///
+    /// An explicit `path` must exist. Without one, [`DEFAULT_CONFIG_FILE`]
+    /// in the current directory is used if present, and the defaults
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// * `ConfigError::Io` - When the config file cannot be read
+    /// * `ConfigError::Parse` - When the config file is not valid JSON
+    /// * `ConfigError::Schema` - When the config file does not match the schema
+    /// * `ConfigError::Pattern` - When an include or exclude pattern is invalid
+    pub fn discover(path: Option<&Path>) -> Result<Self, ConfigError> {
+        match path {
+            Some(path) => Self::load(path),
+            None if Path::new(DEFAULT_CONFIG_FILE).is_file() => {
+                Self::load(Path::new(DEFAULT_CONFIG_FILE))
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Load and validate a config file
+    ///
+    /// Relative directories in the file are resolved against the directory
+    /// containing it.
+    ///
+    /// # Errors
+    ///
+    /// See [`UtamConfig::discover`].
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|source| ConfigError::Io { path: path.to_path_buf(), source })?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        Self::from_json(&content, path, base)
+    }
+
+    /// Parse and validate config file contents
+    fn from_json(content: &str, path: &Path, base: &Path) -> Result<Self, ConfigError> {
+        let json: Value = serde_json::from_str(content)
+            .map_err(|source| ConfigError::Parse { path: path.to_path_buf(), source })?;
+        validate_schema(&json, path)?;
+
+        let mut config: Self = serde_json::from_value(json)
+            .map_err(|source| ConfigError::Parse { path: path.to_path_buf(), source })?;
+        config.check_patterns()?;
+
+        for dir in &mut config.input_directories {
+            *dir = base.join(&*dir);
+        }
+        if let Some(dir) = &mut config.output_directory {
+            *dir = base.join(&*dir);
+        }
+        Ok(config)
+    }
+
+    /// Check that every include and exclude entry is a valid glob pattern
+    fn check_patterns(&self) -> Result<(), ConfigError> {
+        let fields = [("include", &self.include), ("exclude", &self.exclude)];
+        for (field, patterns) in fields {
+            for pattern in patterns {
+                glob::Pattern::new(pattern).map_err(|source| ConfigError::Pattern {
+                    field,
+                    pattern: pattern.clone(),
+                    source,
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Inputs to process: the command line inputs, or the configured directories
+    pub fn inputs(&self, args: &[PathBuf]) -> Vec<PathBuf> {
+        if args.is_empty() {
+            self.input_directories.clone()
+        } else {
+            args.to_vec()
+        }
+    }
+
+    /// Returns true if a discovered file passes the include and exclude patterns
+    ///
+    /// Patterns match against the path relative to the input directory.
+    pub fn selects(&self, file: &InputFile) -> bool {
+        let options = glob::MatchOptions { require_literal_separator: true, ..Default::default() };
+        let matches = |patterns: &[String]| {
+            patterns
+                .iter()
+                .filter_map(|p| glob::Pattern::new(p).ok())
+                .any(|p| p.matches_path_with(&file.relative, options))
+        };
+        matches(&self.include) && !matches(&self.exclude)
+    }
+
+    /// Symbol table of the input files, for resolving custom components
+    ///
+    /// A file belongs to a package when the first directory of its relative
+    /// path is a key of [`UtamConfig::packages`]: `my-app/forms/search.utam.json`
+    /// is `my-app/pageObjects/forms/search`, at the package's mapped module.
+    /// Files that can be read and parsed also record their public members,
+    /// for checking chained compose statements; the others are reported when
+    /// they are compiled. Returns `None` when no input file belongs to a
+    /// package, so custom components keep resolving by their bare struct name.
+    pub fn symbol_table(&self, files: &[InputFile]) -> Option<SymbolTable> {
+        let mut symbols = SymbolTable::new();
+        for file in files {
+            let mut dirs = file
+                .relative
+                .parent()
+                .into_iter()
+                .flat_map(|p| p.components())
+                .map(|c| c.as_os_str().to_string_lossy().into_owned());
+            let Some((package, module)) = dirs.next().and_then(|d| self.packages.get_key_value(&d))
+            else {
+                continue;
+            };
+            let dirs: Vec<String> = dirs.collect();
+            let name = file.page_object_name();
+            let ast = std::fs::read_to_string(&file.path)
+                .ok()
+                .and_then(|json| serde_json::from_str::<PageObjectAst>(&json).ok());
+            match ast {
+                Some(ast) => symbols.insert_page_object(package, &dirs, &name, module, &ast),
+                None => symbols.insert(package, &dirs, &name, module),
+            }
+        }
+        (!symbols.is_empty()).then_some(symbols)
+    }
+
+    /// Code generation settings derived from this config
+    pub fn codegen_config(&self) -> CodeGenConfig {
+        CodeGenConfig {
+            package_modules: self.packages.clone(),
+            wait_timeout: self.timeouts.wait.map(Duration::from_millis),
+            poll_interval: self.timeouts.poll_interval.map(Duration::from_millis),
+            ..Default::default()
+        }
+    }
+}
+
+/// Validate config JSON against the embedded config schema
+fn validate_schema(json: &Value, path: &Path) -> Result<(), ConfigError> {
+    let schema: Value = serde_json::from_str(include_str!("schema/utam-config.json"))
+        .expect("embedded config schema is valid JSON");
+    let validator =
+        jsonschema::draft7::new(&schema).expect("embedded config schema is a valid draft 7 schema");
+
+    let errors: Vec<String> = validator
+        .iter_errors(json)
+        .map(|e| {
+            let location = e.instance_path().to_string();
+            if location.is_empty() {
+                e.to_string()
+            } else {
+                format!("{}: {}", location, e)
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ConfigError::Schema { path: path.to_path_buf(), errors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<UtamConfig, ConfigError> {
+        UtamConfig::from_json(content, Path::new("project/utam.config.json"), Path::new("project"))
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = parse("{}").unwrap();
+        assert_eq!(config, UtamConfig::default());
+        assert_eq!(config.include, vec![DEFAULT_INCLUDE.to_string()]);
+    }
+
+    #[test]
+    fn test_full_config() {
+        let config = parse(
+            r#"{
+                "$schema": "./utam.config.schema.json",
+                "inputDirectories": ["pageobjects"],
+                "outputDirectory": "src/generated",
+                "exclude": ["**/test/**"],
+                "packages": { "utam-lightning": "utam_lightning::pageobjects" },
+                "timeouts": { "wait": 5000, "pollInterval": 100 },
+                "lint": { "rules": { "require-description": "warn" } }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.input_directories, vec![PathBuf::from("project/pageobjects")]);
+        assert_eq!(config.output_directory, Some(PathBuf::from("project/src/generated")));
+        assert_eq!(config.lint.rules["require-description"], Severity::Warn);
+
+        let codegen = config.codegen_config();
+        assert_eq!(codegen.package_modules["utam-lightning"], "utam_lightning::pageobjects");
+        assert_eq!(codegen.wait_timeout, Some(Duration::from_millis(5000)));
+        assert_eq!(codegen.poll_interval, Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_symbol_table_for_packages() {
+        let config = parse(r#"{ "packages": { "my-app": "crate::my_app" } }"#).unwrap();
+        let files = [
+            InputFile::new(
+                PathBuf::from("po/my-app/forms/save-button.utam.json"),
+                Some(Path::new("po")),
+            ),
+            InputFile::new(PathBuf::from("po/my-app/tree.utam.json"), Some(Path::new("po"))),
+            InputFile::new(PathBuf::from("po/other/tree.utam.json"), Some(Path::new("po"))),
+        ];
+
+        let symbols = config.symbol_table(&files).unwrap();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(
+            symbols.resolve("my-app/pageObjects/forms/save-button"),
+            Some("crate::my_app::forms::save_button::SaveButton")
+        );
+        assert_eq!(symbols.resolve("my-app/pageObjects/tree"), Some("crate::my_app::tree::Tree"));
+        assert!(UtamConfig::default().symbol_table(&files).is_none());
+    }
+
+    #[test]
+    fn test_unknown_field_fails_schema() {
+        let error = parse(r#"{ "outputDir": "generated" }"#).unwrap_err();
+        assert!(matches!(error, ConfigError::Schema { .. }));
+        assert!(error.to_string().contains("outputDir"));
+    }
+
+    #[test]
+    fn test_invalid_severity_reports_location() {
+        let error = parse(r#"{ "lint": { "rules": { "no-unused": "fatal" } } }"#).unwrap_err();
+        let ConfigError::Schema { errors, .. } = error else {
+            panic!("Expected schema error, got {:?}", error);
+        };
+        assert!(errors[0].starts_with("/lint/rules/no-unused"));
+    }
+
+    #[test]
+    fn test_invalid_module_path_fails_schema() {
+        let error = parse(r#"{ "packages": { "pkg": "not a path" } }"#).unwrap_err();
+        assert!(matches!(error, ConfigError::Schema { .. }));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        assert!(matches!(parse("{ inputDirectories: }"), Err(ConfigError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let error = parse(r#"{ "exclude": ["[unclosed"] }"#).unwrap_err();
+        assert!(matches!(error, ConfigError::Pattern { field: "exclude", .. }));
+    }
+
+    #[test]
+    fn test_cli_inputs_override_config() {
+        let config = parse(r#"{ "inputDirectories": ["pageobjects"] }"#).unwrap();
+        assert_eq!(config.inputs(&[]), vec![PathBuf::from("project/pageobjects")]);
+        assert_eq!(config.inputs(&[PathBuf::from("other")]), vec![PathBuf::from("other")]);
+    }
+
+    #[test]
+    fn test_selects_include_exclude() {
+        let config = parse(r#"{ "exclude": ["drafts/**"] }"#).unwrap();
+        let file = |relative: &str| InputFile {
+            path: PathBuf::from("root").join(relative),
+            relative: PathBuf::from(relative),
+        };
+
+        assert!(config.selects(&file("login.utam.json")));
+        assert!(config.selects(&file("forms/login.utam.json")));
+        assert!(!config.selects(&file("drafts/login.utam.json")));
+    }
+
+    #[test]
+    fn test_missing_explicit_config() {
+        let error = UtamConfig::discover(Some(Path::new("does-not-exist.json"))).unwrap_err();
+        assert!(matches!(error, ConfigError::Io { .. }));
+    }
+}
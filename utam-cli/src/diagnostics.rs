@@ -0,0 +1,245 @@
+//! Diagnostics shared by `utam validate` and `utam lint`
+//!
+//! A [`Diagnostic`] is one problem at a file, line and column, attributed
+//! to a [`Rule`]. Diagnostics are rendered as compiler-style text, JSON,
+//! or SARIF (see [`crate::sarif`]).
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use console::style;
+use serde::Serialize;
+use utam_compiler::location::pointer_line_col;
+
+use crate::sarif::SarifReport;
+
+/// Output format for diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable `file:line:column` lines
+    Text,
+    /// JSON object with a summary and a diagnostics array
+    Json,
+    /// SARIF 2.1.0 log for GitHub code scanning
+    Sarif,
+}
+
+/// Severity of a diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// Reported but does not fail the command
+    Warning,
+    /// Fails the command
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Warning => write!(f, "warning"),
+            Level::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A check that can produce diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// Stable identifier, used in output and configuration
+    pub id: &'static str,
+    /// One-line description of what the rule checks
+    pub description: &'static str,
+}
+
+/// A problem found in a page object file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// File the problem was found in
+    pub file: PathBuf,
+    /// 1-based line of the problem
+    pub line: usize,
+    /// 1-based column of the problem
+    pub column: usize,
+    /// Severity of the problem
+    pub level: Level,
+    /// ID of the rule that reported the problem
+    #[serde(rename = "rule")]
+    pub rule_id: &'static str,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Create an error diagnostic at the value a JSON pointer refers to
+    pub fn at_pointer(
+        file: &Path,
+        source: &str,
+        pointer: &str,
+        rule: &Rule,
+        message: impl Into<String>,
+    ) -> Self {
+        let (line, column) = pointer_line_col(source, pointer);
+        Self::new(file, line, column, rule, message)
+    }
+
+    /// Create an error diagnostic at a line and column
+    pub fn new(
+        file: &Path,
+        line: usize,
+        column: usize,
+        rule: &Rule,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            line,
+            column,
+            level: Level::Error,
+            rule_id: rule.id,
+            message: message.into(),
+        }
+    }
+
+    /// Change the severity of this diagnostic
+    pub fn with_level(self, level: Level) -> Self {
+        Self { level, ..self }
+    }
+}
+
+/// Counts of diagnostics and files, included in JSON output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    /// Number of files checked
+    pub files: usize,
+    /// Number of files with at least one error
+    pub failed: usize,
+    /// Number of error diagnostics
+    pub errors: usize,
+    /// Number of warning diagnostics
+    pub warnings: usize,
+}
+
+impl Summary {
+    /// Summarize diagnostics over `files` checked files
+    pub fn new(diagnostics: &[Diagnostic], files: usize) -> Self {
+        let errors: Vec<&Diagnostic> =
+            diagnostics.iter().filter(|d| d.level == Level::Error).collect();
+        let mut failed: Vec<&Path> = errors.iter().map(|d| d.file.as_path()).collect();
+        failed.sort();
+        failed.dedup();
+
+        Self {
+            files,
+            failed: failed.len(),
+            errors: errors.len(),
+            warnings: diagnostics.iter().filter(|d| d.level == Level::Warning).count(),
+        }
+    }
+}
+
+/// Render diagnostics in the requested format
+///
+/// `rules` lists every rule that may report, for the SARIF rule table.
+pub fn render(
+    format: OutputFormat,
+    diagnostics: &[Diagnostic],
+    rules: &[Rule],
+    files: usize,
+) -> String {
+    match format {
+        OutputFormat::Text => render_text(diagnostics, files),
+        OutputFormat::Json => render_json(diagnostics, files),
+        OutputFormat::Sarif => SarifReport::new(rules, diagnostics).to_json(),
+    }
+}
+
+/// Render diagnostics as `file:line:column: level[rule]: message` lines
+fn render_text(diagnostics: &[Diagnostic], files: usize) -> String {
+    let mut out = String::new();
+    for d in diagnostics {
+        let level = match d.level {
+            Level::Error => style(d.level).red().bold(),
+            Level::Warning => style(d.level).yellow().bold(),
+        };
+        out.push_str(&format!(
+            "{}:{}:{}: {}[{}]: {}\n",
+            d.file.display(),
+            d.line,
+            d.column,
+            level,
+            d.rule_id,
+            d.message
+        ));
+    }
+
+    let summary = Summary::new(diagnostics, files);
+    out.push_str(&format!(
+        "{} {} file(s): {} error(s), {} warning(s)\n",
+        style("Checked").green().bold(),
+        summary.files,
+        summary.errors,
+        summary.warnings
+    ));
+    out
+}
+
+/// Render diagnostics as a JSON object with `summary` and `diagnostics`
+fn render_json(diagnostics: &[Diagnostic], files: usize) -> String {
+    let output = serde_json::json!({
+        "summary": Summary::new(diagnostics, files),
+        "diagnostics": diagnostics,
+    });
+    serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULE: Rule = Rule { id: "test-rule", description: "A rule for tests" };
+
+    fn diagnostics() -> Vec<Diagnostic> {
+        vec![
+            Diagnostic::new(Path::new("a.utam.json"), 2, 3, &RULE, "first"),
+            Diagnostic::new(Path::new("a.utam.json"), 4, 1, &RULE, "second"),
+            Diagnostic {
+                level: Level::Warning,
+                ..Diagnostic::new(Path::new("b.utam.json"), 1, 1, &RULE, "third")
+            },
+        ]
+    }
+
+    #[test]
+    fn test_summary_counts_failed_files() {
+        let summary = Summary::new(&diagnostics(), 3);
+        assert_eq!(summary, Summary { files: 3, failed: 1, errors: 2, warnings: 1 });
+    }
+
+    #[test]
+    fn test_at_pointer_resolves_position() {
+        let source = "{\n  \"root\": true\n}";
+        let d = Diagnostic::at_pointer(Path::new("a.utam.json"), source, "/root", &RULE, "msg");
+        assert_eq!((d.line, d.column), (2, 11));
+    }
+
+    #[test]
+    fn test_render_text() {
+        console::set_colors_enabled(false);
+        let text = render(OutputFormat::Text, &diagnostics(), &[RULE], 3);
+        assert!(text.contains("a.utam.json:2:3: error[test-rule]: first\n"));
+        assert!(text.contains("b.utam.json:1:1: warning[test-rule]: third\n"));
+        assert!(text.contains("3 file(s): 2 error(s), 1 warning(s)"));
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(OutputFormat::Json, &diagnostics(), &[RULE], 3)).unwrap();
+        assert_eq!(json["summary"]["failed"], 1);
+        assert_eq!(json["diagnostics"][0]["rule"], "test-rule");
+        assert_eq!(json["diagnostics"][0]["line"], 2);
+        assert_eq!(json["diagnostics"][2]["level"], "warning");
+    }
+}
@@ -0,0 +1,119 @@
+//! Error types for the UTAM CLI
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use thiserror::Error;
+
+/// Exit code for I/O and usage errors
+pub const EXIT_ERROR: u8 = 1;
+
+/// Exit code when one or more files fail validation or code generation
+pub const EXIT_VALIDATION_FAILED: u8 = 2;
+
+/// Result type for CLI operations
+pub type CliResult<T> = Result<T, CliError>;
+
+/// Main error type for the UTAM CLI
+#[derive(Error, Debug)]
+pub enum CliError {
+    /// Reading or writing a file failed
+    #[error("I/O error on '{}': {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// An input path did not match any UTAM file
+    #[error("No UTAM files found for input '{}'", path.display())]
+    InputNotFound { path: PathBuf },
+
+    /// Files would be overwritten without `--force`
+    #[error(
+        "Refusing to overwrite existing files (use --force): {}",
+        paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    AlreadyExists { paths: Vec<PathBuf> },
+
+    /// No inputs were given and the config lists no input directories
+    #[error("No inputs given and no inputDirectories in the config file")]
+    NoInputs,
+
+    /// An input was not a valid glob pattern
+    #[error("Invalid glob pattern '{pattern}': {source}")]
+    Pattern {
+        pattern: String,
+        #[source]
+        source: glob::PatternError,
+    },
+
+    /// Two input files would be compiled to the same output file
+    #[error(
+        "'{}' and '{}' would both be compiled to '{}'",
+        first.display(),
+        second.display(),
+        output.display()
+    )]
+    OutputCollision { first: PathBuf, second: PathBuf, output: PathBuf },
+
+    /// The config file could not be loaded
+    #[error(transparent)]
+    Config(#[from] crate::config::ConfigError),
+
+    /// The config enables or configures a lint rule that does not exist
+    #[error("Unknown lint rule '{id}' in the config file (known rules: {known})")]
+    UnknownLintRule { id: String, known: String },
+
+    /// The compiler could not be initialised
+    #[error(transparent)]
+    Compiler(#[from] utam_compiler::CompilerError),
+
+    /// The file system watcher failed
+    #[error("File watcher error: {0}")]
+    Watch(#[from] notify::Error),
+
+    /// One or more files could not be read or written
+    #[error("{failed} of {total} file(s) could not be read or written")]
+    IoFailed { failed: usize, total: usize },
+
+    /// One or more files failed validation or code generation
+    #[error("{failed} of {total} file(s) failed validation")]
+    ValidationFailed { failed: usize, total: usize },
+}
+
+impl CliError {
+    /// Process exit code for this error
+    ///
+    /// Validation failures exit with [`EXIT_VALIDATION_FAILED`] so CI can
+    /// tell a broken page object apart from a broken environment, which
+    /// exits with [`EXIT_ERROR`].
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            CliError::ValidationFailed { .. } => ExitCode::from(EXIT_VALIDATION_FAILED),
+            _ => ExitCode::from(EXIT_ERROR),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code_validation_failed() {
+        let error = CliError::ValidationFailed { failed: 1, total: 3 };
+        assert_eq!(error.exit_code(), ExitCode::from(EXIT_VALIDATION_FAILED));
+        assert_eq!(error.to_string(), "1 of 3 file(s) failed validation");
+    }
+
+    #[test]
+    fn test_exit_code_io_error() {
+        let error = CliError::Io {
+            path: PathBuf::from("missing.utam.json"),
+            source: std::io::Error::from(std::io::ErrorKind::NotFound),
+        };
+        assert_eq!(error.exit_code(), ExitCode::from(EXIT_ERROR));
+        assert!(error.to_string().contains("missing.utam.json"));
+    }
+}
@@ -0,0 +1,246 @@
+//! Input file discovery for CLI commands
+//!
+//! Expands the file, directory and glob arguments passed on the command
+//! line into the list of `.utam.json` files to process.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use utam_compiler::utils::{module_ident, to_pascal_case};
+
+use crate::error::{CliError, CliResult};
+
+/// File extension used by UTAM page object definitions
+pub const UTAM_EXTENSION: &str = ".utam.json";
+
+/// A UTAM page object file discovered from the command line inputs
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InputFile {
+    /// Path to the `.utam.json` file on disk
+    pub path: PathBuf,
+    /// Path relative to the input directory it was found in
+    ///
+    /// Files passed directly or matched by a glob only keep their file name.
+    pub relative: PathBuf,
+}
+
+impl InputFile {
+    /// Create an input file that was found under `base`
+    pub fn new(path: PathBuf, base: Option<&Path>) -> Self {
+        let relative = base
+            .and_then(|b| path.strip_prefix(b).ok())
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from(path.file_name().unwrap_or_default()));
+        Self { path, relative }
+    }
+
+    /// Page object name: the file name without the `.utam.json` suffix
+    pub fn page_object_name(&self) -> String {
+        let file_name = self.path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        file_name.strip_suffix(UTAM_EXTENSION).unwrap_or(&file_name).to_string()
+    }
+
+    /// Rust struct name generated for this page object
+    pub fn struct_name(&self) -> String {
+        to_pascal_case(&self.page_object_name())
+    }
+
+    /// Rust module path for this page object, one segment per directory
+    ///
+    /// `forms/login-form.utam.json` becomes `["forms", "login_form"]`.
+    pub fn module_path(&self) -> Vec<String> {
+        let mut segments: Vec<String> = self
+            .relative
+            .parent()
+            .into_iter()
+            .flat_map(|p| p.components())
+            .map(|c| module_ident(&c.as_os_str().to_string_lossy()))
+            .collect();
+        segments.push(module_ident(&self.page_object_name()));
+        segments
+    }
+
+    /// Path of the generated `.rs` file relative to the output directory
+    pub fn output_path(&self) -> PathBuf {
+        let mut segments = self.module_path();
+        let file = format!("{}.rs", segments.pop().unwrap_or_default());
+        segments.iter().collect::<PathBuf>().join(file)
+    }
+}
+
+/// Expand command line inputs into a sorted, de-duplicated list of UTAM files
+///
+/// Each input may be a `.utam.json` file, a directory (searched recursively)
+/// or a glob pattern. A file reached through several inputs is kept once,
+/// as found by the first of them.
+///
+/// # Errors
+///
+/// * `CliError::Pattern` - When an input is not a valid glob pattern
+/// * `CliError::InputNotFound` - When an input matches no files
+pub fn collect_input_files(inputs: &[PathBuf]) -> CliResult<Vec<InputFile>> {
+    let mut found = Vec::new();
+
+    for input in inputs {
+        if input.is_dir() {
+            let pattern = format!(
+                "{}/**/*{}",
+                glob::Pattern::escape(&input.to_string_lossy()),
+                UTAM_EXTENSION
+            );
+            for path in glob_paths(&pattern)? {
+                found.push(InputFile::new(path, Some(input.as_path())));
+            }
+        } else if input.is_file() {
+            found.push(InputFile::new(input.clone(), None));
+        } else {
+            let matches = glob_paths(&input.to_string_lossy())?;
+            if matches.is_empty() {
+                return Err(CliError::InputNotFound { path: input.clone() });
+            }
+            for path in matches {
+                found.push(InputFile::new(path, None));
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut files = Vec::new();
+    for file in found {
+        let canonical = file
+            .path
+            .canonicalize()
+            .map_err(|source| CliError::Io { path: file.path.clone(), source })?;
+        if seen.insert(canonical) {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Check that no two files are compiled to the same output file
+///
+/// Files passed directly or matched by a glob are placed by file name only,
+/// so `a/button.utam.json` and `b/button.utam.json` would collide.
+///
+/// # Errors
+///
+/// * `CliError::OutputCollision` - When two files share an output path
+pub fn check_output_paths(files: &[InputFile]) -> CliResult<()> {
+    let mut outputs: BTreeMap<PathBuf, &InputFile> = BTreeMap::new();
+    for file in files {
+        if let Some(first) = outputs.insert(file.output_path(), file) {
+            return Err(CliError::OutputCollision {
+                first: first.path.clone(),
+                second: file.path.clone(),
+                output: file.output_path(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Return all regular files matching a glob pattern
+fn glob_paths(pattern: &str) -> CliResult<Vec<PathBuf>> {
+    let paths = glob::glob(pattern)
+        .map_err(|source| CliError::Pattern { pattern: pattern.to_string(), source })?;
+
+    let mut files = Vec::new();
+    for entry in paths {
+        let path =
+            entry.map_err(|e| CliError::Io { path: e.path().to_path_buf(), source: e.into() })?;
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn testdata() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("testdata")
+    }
+
+    #[test]
+    fn test_module_path_nested() {
+        let file = InputFile::new(
+            PathBuf::from("pageobjects/forms/login-form.utam.json"),
+            Some(Path::new("pageobjects")),
+        );
+        assert_eq!(file.page_object_name(), "login-form");
+        assert_eq!(file.struct_name(), "LoginForm");
+        assert_eq!(file.module_path(), vec!["forms", "login_form"]);
+        assert_eq!(file.output_path(), PathBuf::from("forms/login_form.rs"));
+    }
+
+    #[test]
+    fn test_module_path_without_base() {
+        let file = InputFile::new(PathBuf::from("some/dir/salesforceStudioApp.utam.json"), None);
+        assert_eq!(file.module_path(), vec!["salesforce_studio_app"]);
+        assert_eq!(file.struct_name(), "SalesforceStudioApp");
+    }
+
+    #[test]
+    fn test_collect_directory_recursively() {
+        let files = collect_input_files(&[testdata().join("nested")]).unwrap();
+        assert_eq!(files.len(), 2);
+        let nested = files
+            .iter()
+            .find(|f| f.relative == Path::new("forms/login-form.utam.json"))
+            .expect("file in sub-directory collected");
+        assert_eq!(nested.module_path(), vec!["forms", "login_form"]);
+    }
+
+    #[test]
+    fn test_collect_glob_pattern() {
+        let pattern = testdata().join("basic").join("*.utam.json");
+        let files = collect_input_files(&[pattern]).unwrap();
+        assert_eq!(files.len(), 4);
+    }
+
+    #[test]
+    fn test_collect_deduplicates() {
+        let file = testdata().join("basic").join("simple-element.utam.json");
+        let files = collect_input_files(&[file.clone(), file]).unwrap();
+        assert_eq!(files.len(), 1);
+    }
+
+    #[test]
+    fn test_collect_deduplicates_directory_and_file() {
+        let dir = testdata().join("nested");
+        let file = dir.join("forms").join("login-form.utam.json");
+        let files = collect_input_files(&[dir, file]).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|f| f.relative == Path::new("forms/login-form.utam.json")));
+    }
+
+    #[test]
+    fn test_output_collision() {
+        let files = vec![
+            InputFile::new(PathBuf::from("a/button.utam.json"), None),
+            InputFile::new(PathBuf::from("b/button.utam.json"), None),
+        ];
+        let error = check_output_paths(&files).unwrap_err();
+        assert!(matches!(error, CliError::OutputCollision { .. }));
+        assert!(error.to_string().contains("button.rs"));
+    }
+
+    #[test]
+    fn test_no_output_collision_under_directories() {
+        let files = vec![
+            InputFile::new(PathBuf::from("src/a/button.utam.json"), Some(Path::new("src"))),
+            InputFile::new(PathBuf::from("src/b/button.utam.json"), Some(Path::new("src"))),
+        ];
+        assert!(check_output_paths(&files).is_ok());
+    }
+
+    #[test]
+    fn test_collect_missing_input() {
+        let result = collect_input_files(&[testdata().join("does-not-exist.utam.json")]);
+        assert!(matches!(result, Err(CliError::InputNotFound { .. })));
+    }
+}
@@ -0,0 +1,494 @@
+//! Rule-based linter for UTAM page objects
+//!
+//! Each [`LintRule`] inspects a parsed [`PageObjectAst`] and reports
+//! findings as JSON pointers into the source. The [`Linter`] applies the
+//! severities from the `lint.rules` section of `utam.config.json` and turns
+//! findings into [`Diagnostic`]s.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use utam_compiler::{ComposeArgAst, ComposeStatementAst, ElementAst, PageObjectAst, SelectorType};
+
+use crate::config::{LintConfig, Severity};
+use crate::diagnostics::{Diagnostic, Level, Rule};
+use crate::error::{CliError, CliResult};
+
+/// CSS selectors too generic to identify an element reliably
+const GENERIC_SELECTORS: &[&str] = &["*", "div", "span", "p", "section", "ul", "li"];
+
+/// A problem reported by a lint rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// JSON pointer to the offending value
+    pub pointer: String,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl Finding {
+    fn new(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { pointer: pointer.into(), message: message.into() }
+    }
+}
+
+/// A lint rule and the check that implements it
+#[derive(Debug)]
+pub struct LintRule {
+    /// Rule ID and description
+    pub rule: Rule,
+    /// Severity used when the config does not set one
+    pub default_severity: Severity,
+    /// Check returning every finding in a page object
+    pub check: fn(&PageObjectAst) -> Vec<Finding>,
+}
+
+/// Every lint rule, in reporting order
+pub const LINT_RULES: &[LintRule] = &[
+    LintRule {
+        rule: Rule {
+            id: "root-selector",
+            description: "Root page objects declare a selector to load from",
+        },
+        default_severity: Severity::Error,
+        check: root_selector,
+    },
+    LintRule {
+        rule: Rule {
+            id: "duplicate-selector",
+            description: "Elements in the same scope do not share a selector",
+        },
+        default_severity: Severity::Warn,
+        check: duplicate_selector,
+    },
+    LintRule {
+        rule: Rule {
+            id: "generic-selector",
+            description: "Selectors are more specific than a bare tag such as `div`",
+        },
+        default_severity: Severity::Warn,
+        check: generic_selector,
+    },
+    LintRule {
+        rule: Rule { id: "require-description", description: "Public elements have a description" },
+        default_severity: Severity::Warn,
+        check: require_description,
+    },
+    LintRule {
+        rule: Rule {
+            id: "no-unused-elements",
+            description: "Private elements are referenced by a compose statement",
+        },
+        default_severity: Severity::Warn,
+        check: no_unused_elements,
+    },
+    LintRule {
+        rule: Rule {
+            id: "unused-method-args",
+            description: "Method arguments are referenced by the method's compose statements",
+        },
+        default_severity: Severity::Warn,
+        check: unused_method_args,
+    },
+];
+
+/// Runs the enabled lint rules at their configured severities
+#[derive(Debug)]
+pub struct Linter {
+    rules: Vec<(&'static LintRule, Level)>,
+}
+
+impl Linter {
+    /// Create a linter from the `lint` section of the config
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::UnknownLintRule` - When the config names a rule that does not exist
+    pub fn new(config: &LintConfig) -> CliResult<Self> {
+        if let Some(id) =
+            config.rules.keys().find(|id| !LINT_RULES.iter().any(|r| r.rule.id == id.as_str()))
+        {
+            return Err(CliError::UnknownLintRule {
+                id: id.clone(),
+                known: LINT_RULES.iter().map(|r| r.rule.id).collect::<Vec<_>>().join(", "),
+            });
+        }
+
+        let rules = LINT_RULES
+            .iter()
+            .filter_map(|rule| {
+                let severity =
+                    config.rules.get(rule.rule.id).copied().unwrap_or(rule.default_severity);
+                match severity {
+                    Severity::Off => None,
+                    Severity::Warn => Some((rule, Level::Warning)),
+                    Severity::Error => Some((rule, Level::Error)),
+                }
+            })
+            .collect();
+        Ok(Self { rules })
+    }
+
+    /// Rules this linter reports, for the SARIF rule table
+    pub fn rules(&self) -> Vec<Rule> {
+        self.rules.iter().map(|(rule, _)| rule.rule).collect()
+    }
+
+    /// Lint one page object
+    pub fn lint(&self, path: &Path, source: &str, ast: &PageObjectAst) -> Vec<Diagnostic> {
+        self.rules
+            .iter()
+            .flat_map(|(rule, level)| {
+                (rule.check)(ast).into_iter().map(move |finding| {
+                    Diagnostic::at_pointer(
+                        path,
+                        source,
+                        &finding.pointer,
+                        &rule.rule,
+                        finding.message,
+                    )
+                    .with_level(*level)
+                })
+            })
+            .collect()
+    }
+}
+
+/// An element with its JSON pointer and the pointer of the array holding it
+struct ScopedElement<'a> {
+    element: &'a ElementAst,
+    pointer: String,
+    scope: String,
+}
+
+/// Every element in the page object, depth first
+fn all_elements(ast: &PageObjectAst) -> Vec<ScopedElement<'_>> {
+    fn walk<'a>(elements: &'a [ElementAst], scope: String, out: &mut Vec<ScopedElement<'a>>) {
+        for (i, element) in elements.iter().enumerate() {
+            let pointer = format!("{}/{}", scope, i);
+            out.push(ScopedElement { element, pointer: pointer.clone(), scope: scope.clone() });
+            walk(&element.elements, format!("{}/elements", pointer), out);
+            if let Some(shadow) = &element.shadow {
+                walk(&shadow.elements, format!("{}/shadow/elements", pointer), out);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&ast.elements, "/elements".to_string(), &mut out);
+    if let Some(shadow) = &ast.shadow {
+        walk(&shadow.elements, "/shadow/elements".to_string(), &mut out);
+    }
+    out
+}
+
+/// Every compose statement in methods and `beforeLoad`, including predicates
+fn all_statements(ast: &PageObjectAst) -> Vec<&ComposeStatementAst> {
+    fn walk<'a>(statements: &'a [ComposeStatementAst], out: &mut Vec<&'a ComposeStatementAst>) {
+        for statement in statements {
+            out.push(statement);
+            if let Some(predicate) = &statement.predicate {
+                walk(predicate, out);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    for method in &ast.methods {
+        walk(&method.compose, &mut out);
+    }
+    walk(&ast.before_load, &mut out);
+    out
+}
+
+/// Names of all `Named` arguments used by a compose statement
+fn argument_names(statement: &ComposeStatementAst) -> Vec<&str> {
+    let matcher_args = statement.matcher.iter().flat_map(|m| &m.args);
+    let filter_args = statement.filter.iter().flatten().flat_map(|f| &f.matcher.args);
+    let external_args = statement.apply_external.iter().flat_map(|e| &e.args);
+
+    statement
+        .args
+        .iter()
+        .chain(matcher_args)
+        .chain(filter_args)
+        .chain(external_args)
+        .filter_map(|arg| match arg {
+            ComposeArgAst::Named { name, .. } => Some(name.as_str()),
+            ComposeArgAst::Literal { .. } | ComposeArgAst::Value(_) => None,
+        })
+        .chain(statement.predicate.iter().flatten().flat_map(argument_names))
+        .collect()
+}
+
+/// `root-selector`: root page objects need a selector for `load`
+fn root_selector(ast: &PageObjectAst) -> Vec<Finding> {
+    // Interfaces are loaded through the page objects implementing them
+    if ast.root && !ast.is_interface && ast.selector.is_none() {
+        vec![Finding::new("/root", "Root page object has no selector to load it from")]
+    } else {
+        Vec::new()
+    }
+}
+
+/// `duplicate-selector`: two elements in one scope would find the same node
+fn duplicate_selector(ast: &PageObjectAst) -> Vec<Finding> {
+    let mut seen: HashMap<(String, String), &str> = HashMap::new();
+    let mut findings = Vec::new();
+
+    for scoped in all_elements(ast) {
+        let Some(selector) = &scoped.element.selector else { continue };
+        if selector.selector_type() == SelectorType::Unknown {
+            continue;
+        }
+        let key = (scoped.scope.clone(), serde_json::to_string(selector).unwrap_or_default());
+        if let Some(first) = seen.get(&key) {
+            findings.push(Finding::new(
+                format!("{}/selector", scoped.pointer),
+                format!(
+                    "Element '{}' has the same selector as element '{}'",
+                    scoped.element.name, first
+                ),
+            ));
+        } else {
+            seen.insert(key, &scoped.element.name);
+        }
+    }
+    findings
+}
+
+/// `generic-selector`: bare tag selectors match too much of the page
+fn generic_selector(ast: &PageObjectAst) -> Vec<Finding> {
+    let root = ast.selector.as_ref().map(|s| ("/selector".to_string(), "root", s));
+    let elements = all_elements(ast);
+    let element_selectors = elements.iter().filter_map(|scoped| {
+        let selector = scoped.element.selector.as_ref()?;
+        Some((format!("{}/selector", scoped.pointer), scoped.element.name.as_str(), selector))
+    });
+
+    root.into_iter()
+        .chain(element_selectors)
+        .filter_map(|(pointer, name, selector)| {
+            let css = selector.css.as_deref()?.trim();
+            GENERIC_SELECTORS.contains(&css).then(|| {
+                Finding::new(
+                    format!("{}/css", pointer),
+                    format!(
+                        "Selector '{}' of '{}' is too generic; add a class or attribute",
+                        css, name
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// `require-description`: public elements are API and should be documented
+fn require_description(ast: &PageObjectAst) -> Vec<Finding> {
+    all_elements(ast)
+        .into_iter()
+        .filter(|scoped| scoped.element.public && scoped.element.description.is_none())
+        .map(|scoped| {
+            Finding::new(
+                scoped.pointer,
+                format!("Public element '{}' has no description", scoped.element.name),
+            )
+        })
+        .collect()
+}
+
+/// `no-unused-elements`: private elements nothing can reach
+///
+/// Elements with nested or shadow elements are kept, since their children
+/// are located through them.
+fn no_unused_elements(ast: &PageObjectAst) -> Vec<Finding> {
+    let statements = all_statements(ast);
+    let referenced: BTreeSet<&str> = statements
+        .iter()
+        .flat_map(|s| s.element.as_deref().into_iter().chain(argument_names(s)))
+        .collect();
+
+    all_elements(ast)
+        .into_iter()
+        .filter(|scoped| {
+            let element = scoped.element;
+            !element.public
+                && element.elements.is_empty()
+                && element.shadow.is_none()
+                && !referenced.contains(element.name.as_str())
+        })
+        .map(|scoped| {
+            Finding::new(
+                scoped.pointer,
+                format!(
+                    "Private element '{}' is never used by a compose statement",
+                    scoped.element.name
+                ),
+            )
+        })
+        .collect()
+}
+
+/// `unused-method-args`: declared arguments the method body never uses
+fn unused_method_args(ast: &PageObjectAst) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    // Interface methods only declare a signature
+    if ast.is_interface {
+        return findings;
+    }
+    for (i, method) in ast.methods.iter().enumerate() {
+        let used: BTreeSet<&str> = method.compose.iter().flat_map(argument_names).collect();
+        for (j, arg) in method.args.iter().enumerate() {
+            if !used.contains(arg.name.as_str()) {
+                findings.push(Finding::new(
+                    format!("/methods/{}/args/{}", i, j),
+                    format!("Argument '{}' of method '{}' is never used", arg.name, method.name),
+                ));
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn parse(json: &str) -> PageObjectAst {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn findings(check: fn(&PageObjectAst) -> Vec<Finding>, json: &str) -> Vec<Finding> {
+        check(&parse(json))
+    }
+
+    #[test]
+    fn test_root_selector() {
+        let found = findings(root_selector, r#"{ "root": true }"#);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pointer, "/root");
+        assert!(
+            findings(root_selector, r#"{ "root": true, "selector": { "css": "a" } }"#).is_empty()
+        );
+        assert!(findings(root_selector, r#"{ "root": true, "interface": true }"#).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_selector_same_scope_only() {
+        let json = r#"{
+            "elements": [
+                { "name": "first", "selector": { "css": ".item" } },
+                { "name": "second", "selector": { "css": ".item" } },
+                { "name": "box", "selector": { "css": ".box" }, "elements": [
+                    { "name": "inner", "selector": { "css": ".item" } }
+                ]}
+            ]
+        }"#;
+        let found = findings(duplicate_selector, json);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pointer, "/elements/1/selector");
+        assert!(found[0].message.contains("'first'"));
+    }
+
+    #[test]
+    fn test_generic_selector() {
+        let json = r#"{
+            "selector": { "css": "div" },
+            "elements": [
+                { "name": "ok", "selector": { "css": "div.card" } },
+                { "name": "bad", "selector": { "css": " span " } }
+            ]
+        }"#;
+        let pointers: Vec<String> =
+            findings(generic_selector, json).into_iter().map(|f| f.pointer).collect();
+        assert_eq!(pointers, vec!["/selector/css", "/elements/1/selector/css"]);
+    }
+
+    #[test]
+    fn test_require_description() {
+        let json = r#"{
+            "elements": [
+                { "name": "documented", "public": true, "description": "A button", "selector": { "css": "a" } },
+                { "name": "undocumented", "public": true, "selector": { "css": "b" } },
+                { "name": "private", "selector": { "css": "c" } }
+            ]
+        }"#;
+        let found = findings(require_description, json);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pointer, "/elements/1");
+    }
+
+    #[test]
+    fn test_no_unused_elements() {
+        let json = r#"{
+            "elements": [
+                { "name": "used", "selector": { "css": "a" } },
+                { "name": "unused", "selector": { "css": "b" } },
+                { "name": "asArgument", "selector": { "css": "c" } },
+                { "name": "exposed", "public": true, "selector": { "css": "d" } }
+            ],
+            "methods": [{
+                "name": "run",
+                "compose": [
+                    { "element": "used", "apply": "click" },
+                    { "apply": "focus", "args": [{ "name": "asArgument", "type": "elementReference" }] }
+                ]
+            }]
+        }"#;
+        let found = findings(no_unused_elements, json);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pointer, "/elements/1");
+    }
+
+    #[test]
+    fn test_unused_method_args() {
+        let json = r#"{
+            "methods": [{
+                "name": "search",
+                "args": [
+                    { "name": "query", "type": "string" },
+                    { "name": "limit", "type": "number" }
+                ],
+                "compose": [
+                    { "element": "input", "apply": "setText", "args": [{ "name": "query", "type": "argumentReference" }] }
+                ]
+            }]
+        }"#;
+        let found = findings(unused_method_args, json);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pointer, "/methods/0/args/1");
+        assert!(found[0].message.contains("'limit'"));
+
+        let interface = r#"{
+            "interface": true,
+            "methods": [{ "name": "search", "args": [{ "name": "query", "type": "string" }] }]
+        }"#;
+        assert!(findings(unused_method_args, interface).is_empty());
+    }
+
+    #[test]
+    fn test_linter_applies_configured_severity() {
+        let mut rules = BTreeMap::new();
+        rules.insert("generic-selector".to_string(), Severity::Error);
+        rules.insert("require-description".to_string(), Severity::Off);
+        let linter = Linter::new(&LintConfig { rules }).unwrap();
+
+        let source = r#"{ "root": true, "selector": { "css": "div" },
+            "elements": [{ "name": "a", "public": true, "selector": { "css": "a.b" } }] }"#;
+        let diagnostics = linter.lint(Path::new("x.utam.json"), source, &parse(source));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule_id, "generic-selector");
+        assert_eq!(diagnostics[0].level, Level::Error);
+        assert!(!linter.rules().iter().any(|r| r.id == "require-description"));
+    }
+
+    #[test]
+    fn test_linter_rejects_unknown_rule() {
+        let mut rules = BTreeMap::new();
+        rules.insert("no-such-rule".to_string(), Severity::Warn);
+        let error = Linter::new(&LintConfig { rules }).unwrap_err();
+        assert!(matches!(error, CliError::UnknownLintRule { ref id, .. } if id == "no-such-rule"));
+    }
+}
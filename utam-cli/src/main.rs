@@ -2,16 +2,28 @@
 //!
 //! Command-line interface for compiling UTAM page objects to Rust.
 
+mod commands;
+mod config;
+mod diagnostics;
+mod error;
+mod files;
+mod lint;
+mod sarif;
+
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
+use std::process::ExitCode;
+
+use config::UtamConfig;
+use diagnostics::OutputFormat;
 
 #[derive(Parser)]
 #[command(name = "utam")]
 #[command(author, version, about = "UTAM Rust Compiler")]
 struct Cli {
-    /// Path to configuration file
-    #[arg(short, long, default_value = "utam.config.json")]
-    config: PathBuf,
+    /// Path to configuration file [default: utam.config.json if present]
+    #[arg(short, long)]
+    config: Option<PathBuf>,
 
     /// Verbose output
     #[arg(short, long, global = true)]
@@ -25,8 +37,7 @@ struct Cli {
 enum Commands {
     /// Compile UTAM JSON files to Rust
     Compile {
-        /// Input files or directories
-        #[arg(required = true)]
+        /// Input files, directories or glob patterns [default: config inputDirectories]
         input: Vec<PathBuf>,
 
         /// Output directory
@@ -40,13 +51,12 @@ enum Commands {
 
     /// Validate UTAM JSON files
     Validate {
-        /// Files to validate
-        #[arg(required = true)]
+        /// Files to validate [default: config inputDirectories]
         files: Vec<PathBuf>,
 
-        /// Output format (text, json, sarif)
-        #[arg(long, default_value = "text")]
-        format: String,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
 
     /// Initialize configuration
@@ -58,8 +68,7 @@ enum Commands {
 
     /// Lint UTAM JSON files
     Lint {
-        /// Files to lint
-        #[arg(required = true)]
+        /// Files to lint [default: config inputDirectories]
         files: Vec<PathBuf>,
 
         /// Output SARIF report
@@ -68,25 +77,38 @@ enum Commands {
     },
 }
 
-fn main() {
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    match cli.command {
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            commands::report_error(&error);
+            error.exit_code()
+        }
+    }
+}
+
+fn run(cli: Cli) -> error::CliResult<()> {
+    let Cli { config, verbose, command } = cli;
+    let discover = || UtamConfig::discover(config.as_deref());
+
+    match command {
         Commands::Compile { input, output, watch } => {
-            println!("Compiling {:?} -> {:?} (watch: {})", input, output, watch);
-            // TODO: Implement
+            let config = discover()?;
+            if watch {
+                commands::watch::run(&input, output.as_deref(), &config, verbose)
+            } else {
+                commands::compile::run(&input, output.as_deref(), &config, verbose)
+            }
         }
         Commands::Validate { files, format } => {
-            println!("Validating {:?} (format: {})", files, format);
-            // TODO: Implement
-        }
-        Commands::Init { force } => {
-            println!("Initializing config (force: {})", force);
-            // TODO: Implement
+            commands::validate::run(&files, format, &discover()?)
         }
+        // Init runs without a config so that `--force` can replace a broken one
+        Commands::Init { force } => commands::init::run(force, verbose),
         Commands::Lint { files, sarif } => {
-            println!("Linting {:?} (sarif: {:?})", files, sarif);
-            // TODO: Implement
+            commands::lint::run(&files, sarif.as_deref(), &discover()?)
         }
     }
 }
@@ -0,0 +1,191 @@
+//! SARIF 2.1.0 output for GitHub code scanning
+//!
+//! Only the subset of the format that code scanning reads is modelled: a
+//! single run whose tool driver lists the rules, and one result per
+//! diagnostic with a physical location.
+
+use serde::Serialize;
+
+use crate::diagnostics::{Diagnostic, Level, Rule};
+
+/// JSON schema of the SARIF version produced
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF version produced
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Top-level SARIF log
+#[derive(Debug, Serialize)]
+pub struct SarifReport {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+/// A single invocation of the tool
+#[derive(Debug, Serialize)]
+pub struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static str,
+    short_description: SarifMessage,
+}
+
+/// A single problem reported by the tool
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    rule_id: &'static str,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+}
+
+impl SarifReport {
+    /// Build a report with one run covering `diagnostics`
+    pub fn new(rules: &[Rule], diagnostics: &[Diagnostic]) -> Self {
+        let driver = SarifDriver {
+            name: "utam",
+            version: env!("CARGO_PKG_VERSION"),
+            information_uri: env!("CARGO_PKG_REPOSITORY"),
+            rules: rules
+                .iter()
+                .map(|rule| SarifRule {
+                    id: rule.id,
+                    short_description: SarifMessage { text: rule.description.to_string() },
+                })
+                .collect(),
+        };
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool { driver },
+                results: diagnostics.iter().map(SarifResult::from).collect(),
+            }],
+        }
+    }
+
+    /// Serialize the report as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+impl From<&Diagnostic> for SarifResult {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        let level = match diagnostic.level {
+            Level::Error => "error",
+            Level::Warning => "warning",
+        };
+
+        Self {
+            rule_id: diagnostic.rule_id,
+            level,
+            message: SarifMessage { text: diagnostic.message.clone() },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: artifact_uri(&diagnostic.file.to_string_lossy()),
+                    },
+                    region: SarifRegion {
+                        start_line: diagnostic.line,
+                        start_column: diagnostic.column,
+                    },
+                },
+            }],
+        }
+    }
+}
+
+/// Relative URI for a file path, as code scanning expects
+fn artifact_uri(path: &str) -> String {
+    let uri = path.replace('\\', "/");
+    uri.strip_prefix("./").map(str::to_string).unwrap_or(uri)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const RULE: Rule = Rule { id: "schema", description: "Page object matches the UTAM schema" };
+
+    #[test]
+    fn test_sarif_report_shape() {
+        let diagnostics = vec![Diagnostic::new(
+            Path::new("./pageobjects/login.utam.json"),
+            3,
+            5,
+            &RULE,
+            "missing selector",
+        )];
+        let json: serde_json::Value =
+            serde_json::from_str(&SarifReport::new(&[RULE], &diagnostics).to_json()).unwrap();
+
+        assert_eq!(json["version"], "2.1.0");
+        let run = &json["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "utam");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "schema");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "schema");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["message"]["text"], "missing selector");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "pageobjects/login.utam.json");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 5);
+    }
+}
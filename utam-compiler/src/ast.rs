@@ -4,8 +4,12 @@
 //! All types derive Serialize, Deserialize, Debug, and Clone for proper JSON
 //! handling and debugging.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::{AstCheck, AstValidationError};
+
 /// Root page object definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageObjectAst {
@@ -128,7 +132,8 @@ impl<'de> Deserialize<'de> for ElementTypeAst {
                 }
 
                 // Check for known action types
-                const ACTION_TYPES: &[&str] = &["clickable", "editable", "actionable", "draggable"];
+                const ACTION_TYPES: &[&str] =
+                    &["clickable", "editable", "actionable", "draggable", "touchable"];
 
                 if ACTION_TYPES.contains(&value) {
                     // Single action type - wrap in ActionTypes
@@ -195,6 +200,44 @@ pub enum SelectorType {
     Unknown,
 }
 
+impl SelectorType {
+    /// Name of the strategy as written in UTAM JSON
+    pub fn strategy(&self) -> &'static str {
+        match self {
+            SelectorType::Css(_) => "css",
+            SelectorType::AccessibilityId(_) => "accessid",
+            SelectorType::IosClassChain(_) => "classchain",
+            SelectorType::AndroidUiAutomator(_) => "uiautomator",
+            SelectorType::Unknown => "unknown",
+        }
+    }
+
+    /// Selector value, or an empty string for an unknown selector
+    pub fn value(&self) -> &str {
+        match self {
+            SelectorType::Css(value)
+            | SelectorType::AccessibilityId(value)
+            | SelectorType::IosClassChain(value)
+            | SelectorType::AndroidUiAutomator(value) => value,
+            SelectorType::Unknown => "",
+        }
+    }
+
+    /// Whether the strategy can locate elements on a page object platform
+    ///
+    /// `native` page objects use the mobile strategies; every other platform,
+    /// including an undeclared one, is treated as `web` and uses CSS.
+    pub fn supported_on(&self, platform: Option<&str>) -> bool {
+        match self {
+            SelectorType::Css(_) => platform != Some("native"),
+            SelectorType::AccessibilityId(_)
+            | SelectorType::IosClassChain(_)
+            | SelectorType::AndroidUiAutomator(_) => platform == Some("native"),
+            SelectorType::Unknown => true,
+        }
+    }
+}
+
 impl SelectorAst {
     /// Returns the type and value of this selector
     pub fn selector_type(&self) -> SelectorType {
@@ -307,6 +350,13 @@ pub enum ComposeArgAst {
         #[serde(rename = "type")]
         arg_type: String,
     },
+    /// Literal value, optionally typed, such as a `locator`, a
+    /// `frameElement` reference or a `rootPageObject` type
+    Literal {
+        value: serde_json::Value,
+        #[serde(rename = "type", default)]
+        arg_type: Option<String>,
+    },
     Value(serde_json::Value),
 }
 
@@ -319,9 +369,18 @@ pub struct ApplyExternalAst {
 }
 
 /// Filter for element selection
+///
+/// On a list element, `apply` is called on each candidate and the result
+/// tested with `matcher`; `findFirst` keeps only the first match.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilterAst {
+    #[serde(default)]
+    pub apply: Option<String>,
+    #[serde(default)]
+    pub args: Vec<ComposeArgAst>,
     pub matcher: MatcherAst,
+    #[serde(rename = "findFirst", default)]
+    pub find_first: bool,
 }
 
 /// Matcher for filtering elements
@@ -378,19 +437,15 @@ impl CustomComponentRef {
     /// ```
     pub fn parse(s: &str) -> Self {
         let parts: Vec<&str> = s.split('/').collect();
-        
+
         // Handle various formats:
         // - "package/pageObjects/name" -> package="package", path=[], name="name"
         // - "package/pageObjects/path/name" -> package="package", path=["path"], name="name"
         // - "simple-component" (no slashes) -> package="", path=[], name="simple-component"
-        
+
         if parts.len() == 1 {
             // Simple component reference with no package
-            Self {
-                package: String::new(),
-                path: Vec::new(),
-                name: parts[0].to_string(),
-            }
+            Self { package: String::new(), path: Vec::new(), name: parts[0].to_string() }
         } else if parts.len() >= 3 {
             // Full path with package/pageObjects/...
             Self {
@@ -404,11 +459,7 @@ impl CustomComponentRef {
             }
         } else {
             // Fallback: treat as simple name
-            Self {
-                package: String::new(),
-                path: Vec::new(),
-                name: s.to_string(),
-            }
+            Self { package: String::new(), path: Vec::new(), name: s.to_string() }
         }
     }
 
@@ -506,6 +557,7 @@ impl ElementAst {
     /// Checks:
     /// - Element name is a valid Rust identifier
     /// - Frame elements do not have returnAll: true
+    /// - Only list elements have a filter
     /// - Element names are unique within their scope
     ///
     /// # Returns
@@ -528,14 +580,17 @@ impl ElementAst {
         if matches!(self.element_kind(), ElementKind::Frame) {
             if let Some(selector) = &self.selector {
                 if selector.return_all {
-                    errors.push(format!(
-                        "Frame element '{}' cannot have returnAll: true",
-                        self.name
-                    ));
+                    errors
+                        .push(format!("Frame element '{}' cannot have returnAll: true", self.name));
                 }
             }
         }
 
+        // Filters pick from the matches of a list element
+        if self.filter.is_some() && !self.list {
+            errors.push(format!("Element '{}' has a filter but is not a list", self.name));
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
@@ -581,48 +636,197 @@ fn is_valid_rust_identifier(name: &str) -> bool {
     !RUST_KEYWORDS.contains(&name)
 }
 
+/// Check a selector's parameters and that its strategy suits `platform`
+fn validate_selector(
+    selector: &SelectorAst,
+    pointer: &str,
+    platform: Option<&str>,
+    errors: &mut Vec<AstValidationError>,
+) {
+    if let Err(error) = selector.validate() {
+        errors.push(AstValidationError {
+            check: AstCheck::SelectorParameters,
+            path: pointer.to_string(),
+            message: error.to_string(),
+        });
+    }
+    let selector_type = selector.selector_type();
+    if !selector_type.supported_on(platform) {
+        errors.push(AstValidationError {
+            check: AstCheck::PlatformSelector,
+            path: pointer.to_string(),
+            message: format!(
+                "Selector '{}' is not supported on platform '{}'",
+                selector_type.strategy(),
+                platform.unwrap_or("web")
+            ),
+        });
+    }
+}
+
+/// Run element and selector checks on `elements` and everything nested in them
+fn validate_elements(
+    elements: &[ElementAst],
+    base: &str,
+    platform: Option<&str>,
+    errors: &mut Vec<AstValidationError>,
+) {
+    for (i, element) in elements.iter().enumerate() {
+        let pointer = format!("{}/{}", base, i);
+
+        if let Err(messages) = element.validate() {
+            errors.extend(messages.into_iter().map(|message| AstValidationError {
+                check: AstCheck::Element,
+                path: pointer.clone(),
+                message,
+            }));
+        }
+        if let Some(selector) = &element.selector {
+            validate_selector(selector, &format!("{}/selector", pointer), platform, errors);
+        }
+
+        validate_elements(&element.elements, &format!("{}/elements", pointer), platform, errors);
+        if let Some(shadow) = &element.shadow {
+            let base = format!("{}/shadow/elements", pointer);
+            validate_elements(&shadow.elements, &base, platform, errors);
+        }
+    }
+}
+
 impl PageObjectAst {
+    /// Run every check on the page object model
+    ///
+    /// Elements are checked with `ElementAst::validate`, selectors for
+    /// matching parameters and a strategy available on the platform, and
+    /// element names for uniqueness. Each error's
+    /// `path` is the JSON pointer of the value it is about.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if every check passes, otherwise all problems found
+    pub fn validate(&self) -> Result<(), Vec<AstValidationError>> {
+        let mut errors = Vec::new();
+
+        let platform = self.platform.as_deref();
+        if let Some(selector) = &self.selector {
+            validate_selector(selector, "/selector", platform, &mut errors);
+        }
+        validate_elements(&self.elements, "/elements", platform, &mut errors);
+        if let Some(shadow) = &self.shadow {
+            validate_elements(&shadow.elements, "/shadow/elements", platform, &mut errors);
+        }
+        errors.extend(self.duplicate_names().into_iter().map(|(path, message)| {
+            AstValidationError { check: AstCheck::DuplicateName, path, message }
+        }));
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Validate uniqueness of element names within the page object
     ///
-    /// Checks that all element names are unique within:
+    /// Every element gets its getter on the page object itself, so names
+    /// must be unique across all scopes together:
     /// - Top-level elements
     /// - Shadow DOM elements
-    /// - Nested elements
+    /// - Nested elements, including those under a nested shadow root
     ///
     /// # Returns
     ///
     /// `Ok(())` if all names are unique, otherwise returns error messages
     pub fn validate_element_names(&self) -> Result<(), Vec<String>> {
-        let mut errors = Vec::new();
-        let mut names = std::collections::HashSet::new();
-
-        // Validate top-level elements
-        for element in &self.elements {
-            if !names.insert(&element.name) {
-                errors.push(format!(
-                    "Duplicate element name '{}' in top-level elements",
-                    element.name
-                ));
-            }
+        let errors: Vec<String> =
+            self.duplicate_names().into_iter().map(|(_, message)| message).collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
+    }
 
-        // Validate shadow elements
+    /// Duplicate element names, each with the JSON pointer of its `name`
+    fn duplicate_names(&self) -> Vec<(String, String)> {
+        let mut seen = HashMap::new();
+        let mut duplicates = Vec::new();
+        collect_duplicate_names(&self.elements, "/elements", &mut seen, &mut duplicates);
         if let Some(shadow) = &self.shadow {
-            let mut shadow_names = std::collections::HashSet::new();
-            for element in &shadow.elements {
-                if !shadow_names.insert(&element.name) {
-                    errors.push(format!(
-                        "Duplicate element name '{}' in shadow elements",
-                        element.name
-                    ));
-                }
+            collect_duplicate_names(
+                &shadow.elements,
+                "/shadow/elements",
+                &mut seen,
+                &mut duplicates,
+            );
+        }
+        duplicates
+    }
+
+    /// Move `waitFor` predicates written as a function argument into the
+    /// statement's `predicate`
+    ///
+    /// UTAM files pass a predicate as `{"type": "function", "predicate": [...]}`
+    /// in `args`; afterwards every predicate is in `predicate`, in methods and
+    /// in `beforeLoad` alike.
+    pub fn normalize_predicates(&mut self) {
+        let methods = self.methods.iter_mut().flat_map(|method| &mut method.compose);
+        for statement in methods.chain(&mut self.before_load) {
+            statement.normalize_predicate();
+        }
+    }
+}
+
+impl ComposeStatementAst {
+    /// Move a function argument's predicate into `predicate`, recursively
+    fn normalize_predicate(&mut self) {
+        let function = self.args.iter().position(
+            |arg| matches!(arg, ComposeArgAst::Value(value) if value["type"] == "function"),
+        );
+        if let Some(index) = function {
+            if let ComposeArgAst::Value(mut value) = self.args.remove(index) {
+                self.predicate = serde_json::from_value(value["predicate"].take()).ok();
             }
         }
+        for statement in self.predicate.iter_mut().flatten() {
+            statement.normalize_predicate();
+        }
+    }
+}
 
-        if errors.is_empty() {
-            Ok(())
+/// Record the names of `elements` and everything nested in them in `seen`,
+/// pushing each name already declared elsewhere onto `duplicates`
+fn collect_duplicate_names<'a>(
+    elements: &'a [ElementAst],
+    base: &str,
+    seen: &mut HashMap<&'a str, String>,
+    duplicates: &mut Vec<(String, String)>,
+) {
+    for (i, element) in elements.iter().enumerate() {
+        let pointer = format!("{}/{}", base, i);
+        if let Some(first) = seen.get(element.name.as_str()) {
+            duplicates.push((
+                format!("{}/name", pointer),
+                format!("Duplicate element name '{}', already declared at {}", element.name, first),
+            ));
         } else {
-            Err(errors)
+            seen.insert(&element.name, pointer.clone());
+        }
+
+        collect_duplicate_names(
+            &element.elements,
+            &format!("{}/elements", pointer),
+            seen,
+            duplicates,
+        );
+        if let Some(shadow) = &element.shadow {
+            collect_duplicate_names(
+                &shadow.elements,
+                &format!("{}/shadow/elements", pointer),
+                seen,
+                duplicates,
+            );
         }
     }
 }
@@ -700,6 +904,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_element_type_single_touchable() {
+        let json = r#""touchable""#;
+        let elem_type: ElementTypeAst = serde_json::from_str(json).unwrap();
+        match elem_type {
+            ElementTypeAst::ActionTypes(types) => assert_eq!(types, vec!["touchable"]),
+            _ => panic!("Expected ActionTypes variant with touchable"),
+        }
+    }
+
     #[test]
     fn test_element_type_container() {
         let json = r#""container""#;
@@ -731,6 +945,25 @@ mod tests {
         assert!(page.selector.is_some());
     }
 
+    #[test]
+    fn test_normalize_function_predicate() {
+        let json = r#"{
+            "root": true,
+            "selector": {"css": ".app"},
+            "beforeLoad": [{
+                "apply": "waitFor",
+                "args": [{"type": "function", "predicate": [{"element": "root", "apply": "isPresent"}]}]
+            }]
+        }"#;
+        let mut page: PageObjectAst = serde_json::from_str(json).unwrap();
+        page.normalize_predicates();
+        let statement = &page.before_load[0];
+        assert!(statement.args.is_empty());
+        let predicate = statement.predicate.as_ref().unwrap();
+        assert_eq!(predicate[0].element.as_deref(), Some("root"));
+        assert_eq!(predicate[0].apply.as_deref(), Some("isPresent"));
+    }
+
     #[test]
     fn test_round_trip_serialization() {
         let original = PageObjectAst {
@@ -777,6 +1010,9 @@ mod tests {
         match selector.selector_type() {
             SelectorType::Css(s) => assert_eq!(s, "button.submit"),
             _ => panic!("Expected Css selector type"),
+        }
+    }
+
     // Element kind tests
     #[test]
     fn test_element_kind_basic() {
@@ -822,6 +1058,10 @@ mod tests {
         match selector.selector_type() {
             SelectorType::AccessibilityId(s) => assert_eq!(s, "submit-btn"),
             _ => panic!("Expected AccessibilityId selector type"),
+        }
+    }
+
+    #[test]
     fn test_element_kind_typed() {
         let element = ElementAst {
             name: "button".to_string(),
@@ -865,6 +1105,10 @@ mod tests {
         match selector.selector_type() {
             SelectorType::IosClassChain(s) => assert_eq!(s, "XCUIElementTypeButton[1]"),
             _ => panic!("Expected IosClassChain selector type"),
+        }
+    }
+
+    #[test]
     fn test_element_kind_custom() {
         let element = ElementAst {
             name: "customBtn".to_string(),
@@ -909,6 +1153,10 @@ mod tests {
                 assert_eq!(s, "new UiSelector().text(\"Submit\")")
             }
             _ => panic!("Expected AndroidUiAutomator selector type"),
+        }
+    }
+
+    #[test]
     fn test_element_kind_container() {
         let element = ElementAst {
             name: "container".to_string(),
@@ -955,10 +1203,7 @@ mod tests {
             accessid: None,
             classchain: None,
             uiautomator: None,
-            args: vec![SelectorArgAst {
-                name: "id".to_string(),
-                arg_type: "string".to_string(),
-            }],
+            args: vec![SelectorArgAst { name: "id".to_string(), arg_type: "string".to_string() }],
             return_all: false,
         };
 
@@ -1070,10 +1315,7 @@ mod tests {
             accessid: None,
             classchain: None,
             uiautomator: None,
-            args: vec![SelectorArgAst {
-                name: "id".to_string(),
-                arg_type: "string".to_string(),
-            }],
+            args: vec![SelectorArgAst { name: "id".to_string(), arg_type: "string".to_string() }],
             return_all: false,
         };
 
@@ -1088,14 +1330,8 @@ mod tests {
             classchain: None,
             uiautomator: None,
             args: vec![
-                SelectorArgAst {
-                    name: "element_type".to_string(),
-                    arg_type: "string".to_string(),
-                },
-                SelectorArgAst {
-                    name: "index".to_string(),
-                    arg_type: "number".to_string(),
-                },
+                SelectorArgAst { name: "element_type".to_string(), arg_type: "string".to_string() },
+                SelectorArgAst { name: "index".to_string(), arg_type: "number".to_string() },
             ],
             return_all: false,
         };
@@ -1111,14 +1347,8 @@ mod tests {
             classchain: None,
             uiautomator: None,
             args: vec![
-                SelectorArgAst {
-                    name: "id".to_string(),
-                    arg_type: "string".to_string(),
-                },
-                SelectorArgAst {
-                    name: "extra".to_string(),
-                    arg_type: "string".to_string(),
-                },
+                SelectorArgAst { name: "id".to_string(), arg_type: "string".to_string() },
+                SelectorArgAst { name: "extra".to_string(), arg_type: "string".to_string() },
             ],
             return_all: false,
         };
@@ -1155,6 +1385,9 @@ mod tests {
                 assert_eq!(actual, 1);
             }
         }
+    }
+
+    #[test]
     fn test_element_kind_frame() {
         let element = ElementAst {
             name: "iframe".to_string(),
@@ -1426,6 +1659,24 @@ mod tests {
         assert!(element.validate().is_ok());
     }
 
+    #[test]
+    fn test_validate_filter_requires_list() {
+        let json = r#"{
+            "name": "rowByLabel",
+            "selector": { "css": "tr" },
+            "filter": {
+                "apply": "getText",
+                "matcher": { "type": "stringEquals", "args": [{ "name": "label", "type": "string" }] }
+            }
+        }"#;
+        let mut element: ElementAst = serde_json::from_str(json).unwrap();
+        let errors = element.validate().unwrap_err();
+        assert!(errors[0].contains("not a list"));
+
+        element.list = true;
+        assert!(element.validate().is_ok());
+    }
+
     #[test]
     fn test_validate_element_names_unique() {
         let page = PageObjectAst {
@@ -1547,19 +1798,80 @@ mod tests {
         assert!(errors.iter().any(|e| e.contains("Duplicate")));
     }
 
+    #[test]
+    fn test_validate_reports_pointers() {
+        let page: PageObjectAst = serde_json::from_str(
+            r#"{
+                "selector": { "css": ".root" },
+                "elements": [
+                    { "name": "button", "selector": { "css": "b" } },
+                    { "name": "button", "selector": { "css": "c" } },
+                    { "name": "list", "selector": { "css": "ul" }, "elements": [
+                        { "name": "item", "selector": { "css": "li:nth-child(%d)", "args": [
+                            { "name": "index", "type": "number" },
+                            { "name": "extra", "type": "string" }
+                        ]}}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let errors = page.validate().unwrap_err();
+        assert_eq!(
+            errors.iter().map(|e| (e.check, e.path.as_str())).collect::<Vec<_>>(),
+            vec![
+                (AstCheck::SelectorParameters, "/elements/2/elements/0/selector"),
+                (AstCheck::DuplicateName, "/elements/1/name"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_element_names_across_scopes() {
+        let page: PageObjectAst = serde_json::from_str(
+            r#"{
+                "selector": { "css": ".root" },
+                "elements": [
+                    { "name": "button", "selector": { "css": "b" } },
+                    { "name": "list", "selector": { "css": "ul" }, "elements": [
+                        { "name": "button", "selector": { "css": "li b" } }
+                    ], "shadow": { "elements": [
+                        { "name": "list", "selector": { "css": "ol" } }
+                    ]}}
+                ],
+                "shadow": { "elements": [
+                    { "name": "button", "selector": { "css": "s" } }
+                ]}
+            }"#,
+        )
+        .unwrap();
+
+        let errors = page.validate().unwrap_err();
+        assert_eq!(
+            errors.iter().map(|e| e.path.as_str()).collect::<Vec<_>>(),
+            vec![
+                "/elements/1/elements/0/name",
+                "/elements/1/shadow/elements/0/name",
+                "/shadow/elements/0/name",
+            ]
+        );
+        assert!(errors[0].message.contains("already declared at /elements/0"));
+    }
+
     #[test]
     fn test_is_valid_rust_identifier() {
         assert!(super::is_valid_rust_identifier("validName"));
         assert!(super::is_valid_rust_identifier("_private"));
         assert!(super::is_valid_rust_identifier("button123"));
         assert!(super::is_valid_rust_identifier("MyButton"));
-        
+
         // Invalid identifiers
         assert!(!super::is_valid_rust_identifier("123invalid"));
         assert!(!super::is_valid_rust_identifier("invalid-name"));
         assert!(!super::is_valid_rust_identifier("invalid name"));
         assert!(!super::is_valid_rust_identifier(""));
-        
+
         // Rust keywords should be invalid
         assert!(!super::is_valid_rust_identifier("fn"));
         assert!(!super::is_valid_rust_identifier("let"));
@@ -2,8 +2,17 @@
 //!
 //! This module handles transformation of AST types into Rust source code.
 
-use crate::ast::{ComposeArgAst, ComposeStatementAst, ElementAst, MethodArgAst, MethodAst};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::ast::*;
 use crate::error::{CompilerError, CompilerResult};
+use crate::project::SymbolTable;
+use crate::utils::module_ident;
 
 /// Rust method signature
 #[derive(Debug, Clone, PartialEq)]
@@ -43,29 +52,47 @@ pub enum StatementKind {
     /// Get element: self.get_element_name().await?
     GetElement { name: String },
     /// Apply action: element.action(args).await?
-    ApplyAction {
-        action: String,
-        args: Vec<CompiledArg>,
-    },
+    ApplyAction { action: String, args: Vec<CompiledArg> },
     /// Chain from previous: prev.action(args).await?
-    ChainAction {
-        action: String,
-        args: Vec<CompiledArg>,
-    },
-    /// Matcher assertion
-    MatcherAssert {
-        matcher: MatcherKind,
-        value: CompiledArg,
-    },
+    ChainAction { action: String, args: Vec<CompiledArg> },
+    /// Matcher assertion, with the expected value for string matchers
+    MatcherAssert { matcher: MatcherKind, value: Option<CompiledArg> },
 }
 
-/// Matcher types for element filtering
+/// Matcher types for element filtering and assertions
 #[derive(Debug, Clone, PartialEq)]
 pub enum MatcherKind {
     Contains,
     Equals,
+    EqualsIgnoreCase,
     StartsWith,
     EndsWith,
+    IsTrue,
+    IsFalse,
+    NotNull,
+}
+
+impl MatcherKind {
+    /// Parse a matcher type, such as `stringContains` or its short form
+    /// `contains`
+    pub fn parse(matcher_type: &str) -> Option<Self> {
+        match matcher_type {
+            "stringContains" | "contains" => Some(Self::Contains),
+            "stringEquals" | "equals" => Some(Self::Equals),
+            "stringEqualsIgnoreCase" | "equalsIgnoreCase" => Some(Self::EqualsIgnoreCase),
+            "stringStartsWith" | "startsWith" => Some(Self::StartsWith),
+            "stringEndsWith" | "endsWith" => Some(Self::EndsWith),
+            "isTrue" => Some(Self::IsTrue),
+            "isFalse" => Some(Self::IsFalse),
+            "notNull" => Some(Self::NotNull),
+            _ => None,
+        }
+    }
+
+    /// Whether the matcher compares the value against an argument
+    pub fn takes_arg(&self) -> bool {
+        !matches!(self, Self::IsTrue | Self::IsFalse | Self::NotNull)
+    }
 }
 
 impl MethodAst {
@@ -135,35 +162,26 @@ pub fn compile_compose_statements(
                     args: compile_args(&stmt.args, method_args)?,
                 }
             } else {
-                StatementKind::GetElement {
-                    name: element.clone(),
-                }
+                StatementKind::GetElement { name: element.clone() }
             }
         } else if let Some(matcher) = &stmt.matcher {
             // Matcher assertion
-            let matcher_kind = match matcher.matcher_type.as_str() {
-                "contains" => MatcherKind::Contains,
-                "equals" => MatcherKind::Equals,
-                "startsWith" => MatcherKind::StartsWith,
-                "endsWith" => MatcherKind::EndsWith,
-                _ => {
-                    return Err(CompilerError::InvalidStatement(format!(
-                        "Unknown matcher type: {}",
-                        matcher.matcher_type
-                    )))
-                }
+            let Some(matcher_kind) = MatcherKind::parse(&matcher.matcher_type) else {
+                return Err(CompilerError::InvalidStatement(format!(
+                    "Unknown matcher type: {}",
+                    matcher.matcher_type
+                )));
             };
-            let value = if let Some(first_arg) = matcher.args.first() {
-                compile_single_arg(first_arg, method_args)?
-            } else {
-                return Err(CompilerError::InvalidStatement(
-                    "Matcher requires an argument".to_string(),
-                ));
+            let value = match (matcher_kind.takes_arg(), matcher.args.first()) {
+                (true, Some(first_arg)) => Some(compile_single_arg(first_arg, method_args)?),
+                (true, None) => {
+                    return Err(CompilerError::InvalidStatement(
+                        "Matcher requires an argument".to_string(),
+                    ));
+                }
+                (false, _) => None,
             };
-            StatementKind::MatcherAssert {
-                matcher: matcher_kind,
-                value,
-            }
+            StatementKind::MatcherAssert { matcher: matcher_kind, value }
         } else {
             return Err(CompilerError::InvalidStatement(format!(
                 "Invalid statement at index {}",
@@ -171,10 +189,7 @@ pub fn compile_compose_statements(
             )));
         };
 
-        compiled.push(CompiledStatement {
-            kind,
-            return_type: stmt.return_type.clone(),
-        });
+        compiled.push(CompiledStatement { kind, return_type: stmt.return_type.clone() });
     }
 
     Ok(compiled)
@@ -198,9 +213,7 @@ fn compile_args(
     args: &[ComposeArgAst],
     method_args: &[MethodArgAst],
 ) -> CompilerResult<Vec<CompiledArg>> {
-    args.iter()
-        .map(|arg| compile_single_arg(arg, method_args))
-        .collect()
+    args.iter().map(|arg| compile_single_arg(arg, method_args)).collect()
 }
 
 /// Compile a single ComposeArgAst into a CompiledArg, validating argument references
@@ -217,7 +230,10 @@ fn compile_args(
 /// # Errors
 ///
 /// Returns `InvalidStatement` if an argument reference is not found in method arguments
-fn compile_single_arg(arg: &ComposeArgAst, method_args: &[MethodArgAst]) -> CompilerResult<CompiledArg> {
+fn compile_single_arg(
+    arg: &ComposeArgAst,
+    method_args: &[MethodArgAst],
+) -> CompilerResult<CompiledArg> {
     match arg {
         ComposeArgAst::Named { name, arg_type } => {
             // Check if this is an argumentReference
@@ -236,14 +252,12 @@ fn compile_single_arg(arg: &ComposeArgAst, method_args: &[MethodArgAst]) -> Comp
                 Ok(CompiledArg::ArgumentReference(name.clone()))
             }
         }
-        ComposeArgAst::Value(v) => {
+        ComposeArgAst::Value(v) | ComposeArgAst::Literal { value: v, .. } => {
             // Literal value
             let literal = if v.is_string() {
                 format!("\"{}\"", v.as_str().unwrap_or(""))
             } else if v.is_boolean() {
                 v.as_bool().unwrap_or(false).to_string()
-            } else if v.is_number() {
-                v.to_string()
             } else {
                 v.to_string()
             };
@@ -255,9 +269,8 @@ fn compile_single_arg(arg: &ComposeArgAst, method_args: &[MethodArgAst]) -> Comp
 /// Convert a string to snake_case
 pub fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
-    let mut chars = s.chars().peekable();
 
-    while let Some(c) = chars.next() {
+    for c in s.chars() {
         if c.is_uppercase() {
             if !result.is_empty() {
                 result.push('_');
@@ -288,23 +301,12 @@ pub fn to_pascal_case(s: &str) -> String {
     }
 
     result
-//! Generates Rust source code from parsed AST using the quote crate.
-
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-
-use crate::ast::*;
-use crate::error::{CompilerError, CompilerResult};
-use crate::utils::{to_pascal_case, to_snake_case};//! Codegen module for generating Rust code from UTAM AST
-//!
-//! This module provides functions to generate Rust code from parsed UTAM page objects.
-
-use crate::ast::SelectorAst;
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+}
 
 /// Generates Rust code for a selector, handling parameterized selectors
 ///
+/// CSS selectors become a thirtyfour `By`; mobile strategies become a
+/// `utam_core::selector::Selector`, located through the `Locate` trait.
 /// For parameterized selectors (with args), generates a format! call that
 /// substitutes %s and %d placeholders with the provided arguments.
 ///
@@ -319,78 +321,458 @@ use quote::{format_ident, quote};
 /// ```text
 /// By::Css(&format!("button[data-id='{}']", button_id))
 /// ```
+///
+/// Mobile selector:
+/// ```text
+/// Selector::AccessibilityId("submit".to_string())
+/// ```
 pub fn generate_selector_code(selector: &SelectorAst) -> TokenStream {
-    if selector.has_parameters() {
-        // Get the selector string - we only support CSS for now with parameters
-        let template = match selector.css.as_ref() {
-            Some(css) => css,
-            None => {
-                // For non-CSS selectors with parameters, we'll need to handle them later
-                return quote! { compile_error!("Parameterized selectors only supported for CSS") };
-            }
-        };
+    let (template, variant) = match selector.selector_type() {
+        SelectorType::Css(css) => (css, None),
+        SelectorType::AccessibilityId(value) => (value, Some(quote! { AccessibilityId })),
+        SelectorType::IosClassChain(value) => (value, Some(quote! { IosClassChain })),
+        SelectorType::AndroidUiAutomator(value) => (value, Some(quote! { AndroidUiAutomator })),
+        SelectorType::Unknown => {
+            return quote! { compile_error!("Selector must have at least one selector type") };
+        }
+    };
 
+    let value = if selector.has_parameters() {
         // Generate the argument list
         let args: Vec<_> = selector
             .args
             .iter()
             .map(|a| {
-                let name = format_ident!("{}", a.name);
+                let name = format_ident!("{}", to_snake_case(&a.name));
                 quote! { #name }
             })
             .collect();
 
         // Replace %s and %d with {} for format!
         let format_str = template.replace("%s", "{}").replace("%d", "{}");
-
-        quote! {
-            thirtyfour::By::Css(&format!(#format_str, #(#args),*))
-        }
+        quote! { format!(#format_str, #(#args),*) }
     } else {
-        // Simple selector without parameters
-        if let Some(css) = &selector.css {
-            quote! { thirtyfour::By::Css(#css) }
-        } else if let Some(accessid) = &selector.accessid {
-            quote! { thirtyfour::By::Id(#accessid) }
-        } else if let Some(classchain) = &selector.classchain {
-            quote! { thirtyfour::By::IosClassChain(#classchain) }
-        } else if let Some(uiautomator) = &selector.uiautomator {
-            quote! { thirtyfour::By::AndroidUiAutomator(#uiautomator) }
-        } else {
-            quote! { compile_error!("Selector must have at least one selector type") }
-        }
+        quote! { #template }
+    };
+
+    match variant {
+        None if selector.has_parameters() => quote! { thirtyfour::By::Css(&#value) },
+        None => quote! { thirtyfour::By::Css(#value) },
+        Some(variant) if selector.has_parameters() => quote! { Selector::#variant(#value) },
+        Some(variant) => quote! { Selector::#variant(#value.to_string()) },
     }
+}
 
+/// Generates a `Selector` for a literal `locator` argument
+///
+/// Unlike element selectors, CSS is wrapped in `Selector` too, since the
+/// argument is passed to runtime functions taking any strategy.
+fn generate_selector_value(selector: &SelectorAst) -> TokenStream {
+    let (value, variant) = match selector.selector_type() {
+        SelectorType::Css(css) => (css, quote! { Css }),
+        SelectorType::AccessibilityId(value) => (value, quote! { AccessibilityId }),
+        SelectorType::IosClassChain(value) => (value, quote! { IosClassChain }),
+        SelectorType::AndroidUiAutomator(value) => (value, quote! { AndroidUiAutomator }),
+        SelectorType::Unknown => {
+            return quote! { compile_error!("Selector must have at least one selector type") };
+        }
+    };
+    quote! { Selector::#variant(#value.to_string()) }
+}
+
+/// Whether an element is located with CSS rather than a mobile strategy
+///
+/// Elements without a selector count as CSS, matching the empty selector
+/// the generator falls back to.
+fn uses_css(selector: Option<&SelectorAst>) -> bool {
+    selector
+        .is_none_or(|s| matches!(s.selector_type(), SelectorType::Css(_) | SelectorType::Unknown))
+}
 
 /// Configuration for code generation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CodeGenConfig {
     /// Module name for the generated code
     pub module_name: Option<String>,
+    /// Rust module paths for custom component packages, keyed by package name
+    ///
+    /// A custom component `utam-lightning/pageObjects/tree` resolves to
+    /// `utam_lightning::pageobjects::Tree` when `utam-lightning` maps to
+    /// `utam_lightning::pageobjects`. Unmapped packages resolve to the bare
+    /// struct name.
+    pub package_modules: BTreeMap<String, String>,
+    /// Timeout for locating the root element in `RootPageObject::load`
+    ///
+    /// When `None`, the root element is looked up once without waiting.
+    pub wait_timeout: Option<Duration>,
+    /// Poll interval for generated waits, defaulting to `WaitConfig`'s
+    pub poll_interval: Option<Duration>,
+    /// Page objects compiled together with this one
+    ///
+    /// Custom components found here are referenced by their fully-qualified
+    /// path, ahead of `package_modules`.
+    pub symbols: Option<Arc<SymbolTable>>,
+}
+
+/// An element together with the elements enclosing it
+struct ScopedElement<'a> {
+    element: &'a ElementAst,
+    /// Enclosing elements, outermost first, each with its own `in_shadow`
+    ancestors: Vec<(&'a ElementAst, bool)>,
+    /// Whether the element is declared under its parent's `shadow`
+    in_shadow: bool,
+}
+
+impl<'a> ScopedElement<'a> {
+    /// Selector arguments needed to locate the element: those of its
+    /// enclosing elements, outermost first, then its own
+    fn selector_args(&self) -> Vec<&'a SelectorArgAst> {
+        self.ancestors
+            .iter()
+            .map(|(ancestor, _)| *ancestor)
+            .chain(std::iter::once(self.element))
+            .filter_map(|element| element.selector.as_ref())
+            .flat_map(|selector| &selector.args)
+            .collect()
+    }
+
+    /// Getter parameters as `(name, type)`: the selector arguments, then
+    /// the named arguments of the element's filter
+    fn params(&self) -> Vec<(&'a str, &'a str)> {
+        let filter_args = self
+            .element
+            .filter
+            .iter()
+            .flat_map(|filter| filter.args.iter().chain(&filter.matcher.args))
+            .filter_map(|arg| match arg {
+                ComposeArgAst::Named { name, arg_type } => Some((name.as_str(), arg_type.as_str())),
+                ComposeArgAst::Literal { .. } | ComposeArgAst::Value(_) => None,
+            });
+        self.selector_args()
+            .into_iter()
+            .map(|arg| (arg.name.as_str(), arg.arg_type.as_str()))
+            .chain(filter_args)
+            .collect()
+    }
+
+    /// Index in `ancestors` of the frame the element is declared in, if any
+    fn frame(&self) -> Option<usize> {
+        self.ancestors
+            .iter()
+            .position(|(ancestor, _)| matches!(ancestor.element_type, Some(ElementTypeAst::Frame)))
+    }
+}
+
+/// Whether an element's getter returns an `ElementList`
+///
+/// A list element returns every match, unless its filter keeps only the
+/// first one.
+fn returns_vec(element: &ElementAst) -> bool {
+    element.list && !element.filter.as_ref().is_some_and(|filter| filter.find_first)
+}
+
+/// Whether an element is a container, whose getter is generic over the
+/// page object it loads
+fn is_container(element: &ElementAst) -> bool {
+    matches!(element.element_type, Some(ElementTypeAst::Container))
+}
+
+/// Condition testing a `value`, or `None` for an unsupported matcher
+fn matcher_condition(matcher: &MatcherAst, arg: Option<&TokenStream>) -> Option<TokenStream> {
+    match (MatcherKind::parse(&matcher.matcher_type)?, arg) {
+        (MatcherKind::IsTrue, None) => Some(quote! { value }),
+        (MatcherKind::IsFalse, None) => Some(quote! { !value }),
+        (MatcherKind::NotNull, None) => Some(quote! { value.is_some() }),
+        (MatcherKind::Contains, Some(arg)) => Some(quote! { value.contains(#arg) }),
+        (MatcherKind::Equals, Some(arg)) => Some(quote! { value == #arg }),
+        (MatcherKind::EqualsIgnoreCase, Some(arg)) => {
+            Some(quote! { value.to_lowercase() == #arg.to_lowercase() })
+        }
+        (MatcherKind::StartsWith, Some(arg)) => Some(quote! { value.starts_with(#arg) }),
+        (MatcherKind::EndsWith, Some(arg)) => Some(quote! { value.ends_with(#arg) }),
+        _ => None,
+    }
+}
+
+/// Whether a matcher can test a value of Rust type `value_type`
+///
+/// Boolean matchers need a `bool`, `notNull` an `Option` and the string
+/// matchers a `String`. Unknown matchers are left to the check for a
+/// supported matcher.
+fn matcher_accepts(matcher_type: &str, value_type: &TokenStream) -> bool {
+    let value_type = type_name(value_type);
+    match MatcherKind::parse(matcher_type) {
+        Some(MatcherKind::IsTrue | MatcherKind::IsFalse) => value_type == "bool",
+        Some(MatcherKind::NotNull) => value_type.starts_with("Option<"),
+        Some(_) => value_type == "String",
+        None => true,
+    }
+}
+
+/// Description of the values a matcher accepts, as a `String` expression
+fn matcher_expectation(kind: &MatcherKind, arg: Option<&TokenStream>) -> TokenStream {
+    let (template, arg) = match (kind, arg) {
+        (MatcherKind::IsTrue, _) => return quote! { "true".to_string() },
+        (MatcherKind::IsFalse, _) => return quote! { "false".to_string() },
+        (MatcherKind::NotNull, _) => return quote! { "a value".to_string() },
+        (_, None) => return quote! { String::new() },
+        (MatcherKind::Contains, Some(arg)) => ("a string containing {:?}", arg),
+        (MatcherKind::Equals, Some(arg)) => ("{:?}", arg),
+        (MatcherKind::EqualsIgnoreCase, Some(arg)) => ("{:?} ignoring case", arg),
+        (MatcherKind::StartsWith, Some(arg)) => ("a string starting with {:?}", arg),
+        (MatcherKind::EndsWith, Some(arg)) => ("a string ending with {:?}", arg),
+    };
+    quote! { format!(#template, #arg) }
+}
+
+/// What the result of a compose statement can be chained to
+enum ChainSource {
+    /// A basic element with these action types
+    Element(Vec<String>),
+    /// A custom component with this type path
+    Component(String),
+    /// A value that has no elements or methods
+    Value(String),
+}
+
+/// Actions available on a basic element with the given action types
+///
+/// Every element is actionable and has the base element queries and waits.
+fn element_actions(types: &[String]) -> Vec<&'static str> {
+    let mut actions = vec![
+        "getText",
+        "getAttribute",
+        "getClassAttribute",
+        "getCssPropertyValue",
+        "getRect",
+        "getTitle",
+        "getValue",
+        "isEnabled",
+        "isFocused",
+        "isPresent",
+        "isVisible",
+        "containsElement",
+        "waitForVisible",
+        "waitForInvisible",
+        "waitForAbsence",
+        "waitForEnabled",
+        "focus",
+        "blur",
+        "scrollIntoView",
+        "scrollToCenter",
+        "scrollToTop",
+        "moveTo",
+    ];
+    for action_type in types {
+        match action_type.as_str() {
+            "clickable" => actions.extend(["click", "doubleClick", "rightClick", "clickAndHold"]),
+            "editable" => actions.extend(["clear", "setText", "clearAndType", "press"]),
+            "draggable" => {
+                actions.extend(["dragAndDrop", "dragAndDropWithDuration", "dragAndDropByOffset"])
+            }
+            "touchable" => actions.extend(["tap", "longPress", "flick", "swipe"]),
+            _ => {}
+        }
+    }
+    actions
+}
+
+/// Statements and, after each, the statements of its predicate
+fn with_predicates(statements: &[ComposeStatementAst]) -> Vec<&ComposeStatementAst> {
+    let mut out = Vec::new();
+    for stmt in statements {
+        out.push(stmt);
+        if let Some(predicate) = &stmt.predicate {
+            out.extend(with_predicates(predicate));
+        }
+    }
+    out
+}
+
+/// Whether the last of a list of statements, or the last statement of its
+/// predicate, is chained to the statement before it
+fn ends_in_chain(statements: &[ComposeStatementAst]) -> bool {
+    statements
+        .last()
+        .is_some_and(|last| last.chain || last.predicate.as_deref().is_some_and(ends_in_chain))
+}
+
+/// Element actions that return a `bool`
+const BOOLEAN_ACTIONS: &[&str] =
+    &["isEnabled", "isFocused", "isPresent", "isVisible", "containsElement"];
+
+/// Rust type a basic element action returns, or `None` for an unknown action
+fn action_return_type(action: &str) -> Option<TokenStream> {
+    match action {
+        "getText" | "getClassAttribute" | "getCssPropertyValue" | "getTitle" | "getValue" => {
+            Some(quote! { String })
+        }
+        "getAttribute" => Some(quote! { Option<String> }),
+        "getRect" => Some(quote! { ElementRectangle }),
+        _ if BOOLEAN_ACTIONS.contains(&action) => Some(quote! { bool }),
+        _ => {
+            let all_types = ["clickable", "editable", "draggable", "touchable"].map(String::from);
+            element_actions(&all_types).contains(&action).then(|| quote! { () })
+        }
+    }
+}
+
+/// Action types with a capability, each with its `Element` marker and the
+/// alias of an element with only that capability, in `Element`'s order
+const CAPABILITIES: [(&str, &str, &str); 4] = [
+    ("clickable", "Click", "ClickableElement"),
+    ("editable", "Edit", "EditableElement"),
+    ("draggable", "Drag", "DraggableElement"),
+    ("touchable", "Touch", "TouchableElement"),
+];
+
+/// Element wrapper for a list of action types
+///
+/// The wrapper has the union of the types' capabilities: `BaseElement`
+/// for none, the single-capability alias for one, and `Element` with a
+/// tuple of markers for several.
+fn action_types_wrapper(types: &[String]) -> TokenStream {
+    element_wrapper(types, false)
+}
+
+/// Element wrapper for a list of action types, in expression position
+fn action_types_constructor(types: &[String]) -> TokenStream {
+    element_wrapper(types, true)
+}
+
+/// Path of the element wrapper for a list of action types, with a
+/// turbofish for use in expressions if `turbofish`
+fn element_wrapper(types: &[String], turbofish: bool) -> TokenStream {
+    let capabilities: Vec<_> = CAPABILITIES
+        .iter()
+        .filter(|(action_type, ..)| types.iter().any(|t| t == action_type))
+        .collect();
+    match capabilities.as_slice() {
+        [] => quote! { BaseElement },
+        [(_, _, alias)] => {
+            let alias = format_ident!("{}", alias);
+            quote! { #alias }
+        }
+        several => {
+            let markers = several.iter().map(|(_, marker, _)| format_ident!("{}", marker));
+            if turbofish {
+                quote! { Element::<(#(#markers),*)> }
+            } else {
+                quote! { Element<(#(#markers),*)> }
+            }
+        }
+    }
+}
+
+/// Rust literal for a JSON value in compose arguments
+fn json_literal(value: &serde_json::Value) -> TokenStream {
+    match value {
+        serde_json::Value::String(s) => quote! { #s },
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                quote! { #i }
+            } else if let Some(f) = n.as_f64() {
+                quote! { #f }
+            } else {
+                quote! { 0 }
+            }
+        }
+        serde_json::Value::Bool(b) => quote! { #b },
+        _ => quote! { () },
+    }
+}
+
+/// Type path of the page object a statement's `rootPageObject` argument names
+fn page_object_arg(args: &[ComposeArgAst]) -> Option<&str> {
+    args.iter().find_map(|arg| match arg {
+        ComposeArgAst::Literal { value, arg_type } if is_page_object_type(arg_type.as_deref()) => {
+            value.as_str()
+        }
+        _ => None,
+    })
+}
+
+/// Whether a literal argument's type names a page object type rather than a value
+fn is_page_object_type(arg_type: Option<&str>) -> bool {
+    matches!(arg_type, Some("pageObject" | "rootPageObject"))
+}
+
+/// Whether any of `statements`, or their predicates, uses `document`
+fn uses_document(statements: &[ComposeStatementAst]) -> bool {
+    uses_element(statements, "document")
+}
+
+/// Whether any of `statements`, or their predicates, gets the element `name`
+fn uses_element(statements: &[ComposeStatementAst], name: &str) -> bool {
+    with_predicates(statements).iter().any(|stmt| stmt.element.as_deref() == Some(name))
 }
 
-impl Default for CodeGenConfig {
-    fn default() -> Self {
-        Self { module_name: None }
+/// Type as written in messages, without the spaces `TokenStream` adds
+fn type_name(rust_type: &TokenStream) -> String {
+    rust_type.to_string().replace(' ', "")
+}
+
+/// Collect `elements` and everything nested in them, depth first
+fn collect_scoped<'a>(
+    elements: &'a [ElementAst],
+    ancestors: &[(&'a ElementAst, bool)],
+    in_shadow: bool,
+    out: &mut Vec<ScopedElement<'a>>,
+) {
+    for element in elements {
+        out.push(ScopedElement { element, ancestors: ancestors.to_vec(), in_shadow });
+
+        let mut path = ancestors.to_vec();
+        path.push((element, in_shadow));
+        collect_scoped(&element.elements, &path, false, out);
+        if let Some(shadow) = &element.shadow {
+            collect_scoped(&shadow.elements, &path, true, out);
+        }
     }
 }
 
+/// CSS selector of an element, or an empty string
+fn css_selector(element: &ElementAst) -> &str {
+    element.selector.as_ref().and_then(|s| s.css.as_ref()).map(|s| s.as_str()).unwrap_or("")
+}
+
 /// Main code generator
 pub struct CodeGenerator {
     ast: PageObjectAst,
     config: CodeGenConfig,
+    /// Whether statements are generated for `beforeLoad`, which runs before
+    /// the root element is found and so looks it up on each use
+    before_load: bool,
 }
 
 impl CodeGenerator {
     /// Create a new code generator
     pub fn new(ast: PageObjectAst, config: CodeGenConfig) -> Self {
-        Self { ast, config }
+        let mut ast = ast;
+        ast.normalize_predicates();
+        Self { ast, config, before_load: false }
     }
 
     /// Generate Rust source code from AST
     pub fn generate(&self) -> CompilerResult<String> {
+        self.check_platform()?;
+        self.check_filters()?;
+        self.check_frames()?;
+        self.check_containers()?;
+        self.check_loads()?;
+        self.check_chains()?;
+        self.check_element_names()?;
+        self.check_getter_params()?;
+        self.check_method_names()?;
+        self.check_matchers()?;
+        self.check_return_types()?;
+        self.check_document()?;
+
         let struct_name = self.struct_name();
         let struct_name_ident = format_ident!("{}", struct_name);
+        if self.ast.is_interface {
+            return self.format(self.generate_interface(&struct_name_ident));
+        }
 
         let struct_def = self.generate_struct(&struct_name_ident);
         let page_object_impl = self.generate_page_object_impl(&struct_name_ident);
@@ -401,6 +783,11 @@ impl CodeGenerator {
         };
         let element_getters = self.generate_element_getters(&struct_name_ident);
         let methods = self.generate_methods(&struct_name_ident);
+        let interface_impl = self
+            .ast
+            .implements
+            .as_ref()
+            .map(|interface| self.generate_interface_impl(&struct_name_ident, interface));
 
         let tokens = quote! {
             use utam_core::prelude::*;
@@ -415,14 +802,584 @@ impl CodeGenerator {
                 #element_getters
                 #methods
             }
+
+            #interface_impl
         };
 
-        // Format with prettyplease
-        let syntax_tree = syn::parse2(tokens)
-            .map_err(|e| CompilerError::Compilation(format!("Failed to parse generated tokens: {}", e)))?;
+        self.format(tokens)
+    }
+
+    /// Format generated tokens with prettyplease
+    fn format(&self, tokens: TokenStream) -> CompilerResult<String> {
+        let syntax_tree = syn::parse2(tokens).map_err(|e| {
+            CompilerError::Compilation(format!("Failed to parse generated tokens: {}", e))
+        })?;
         Ok(prettyplease::unparse(&syntax_tree))
     }
 
+    /// Generate an interface page object as a trait
+    ///
+    /// The trait declares the public element getters and the methods; page
+    /// objects that `implements` the interface provide them.
+    fn generate_interface(&self, trait_name: &proc_macro2::Ident) -> TokenStream {
+        let doc = self.generate_doc_comment();
+        let root_getter = self.ast.expose_root_element.then(|| {
+            let signature = self.root_getter_signature();
+            quote! { #[doc = "Get the root element"] #signature; }
+        });
+        let getters = self.public_elements().into_iter().map(|scoped| {
+            let doc = self.getter_doc(scoped.element);
+            let signature = self.getter_signature(&scoped);
+            quote! { #doc #signature; }
+        });
+        let methods = self.ast.methods.iter().map(|method| {
+            let doc = self.method_doc(method);
+            let signature = self.method_signature(method);
+            quote! { #doc #signature; }
+        });
+
+        quote! {
+            use utam_core::prelude::*;
+
+            #doc
+            #[async_trait]
+            pub trait #trait_name: PageObject {
+                #root_getter
+                #(#getters)*
+                #(#methods)*
+            }
+        }
+    }
+
+    /// Generate the impl of the interface named by `implements`
+    ///
+    /// Each public element getter and method delegates to the inherent one,
+    /// so the page object's public API must match the interface.
+    fn generate_interface_impl(
+        &self,
+        struct_name: &proc_macro2::Ident,
+        interface: &str,
+    ) -> TokenStream {
+        let trait_path = self.custom_component_type(interface);
+        let root_getter = self.ast.expose_root_element.then(|| {
+            let signature = self.root_getter_signature();
+            quote! {
+                #signature {
+                    #struct_name::get_root(self).await
+                }
+            }
+        });
+        let getters = self.public_elements().into_iter().map(|scoped| {
+            let signature = self.getter_signature(&scoped);
+            let method_name = format_ident!("get_{}", to_snake_case(&scoped.element.name));
+            let args = scoped
+                .params()
+                .into_iter()
+                .map(|(name, _)| format_ident!("{}", to_snake_case(name)));
+            quote! {
+                #signature {
+                    #struct_name::#method_name(self #(, #args)*).await
+                }
+            }
+        });
+        let methods = self.ast.methods.iter().map(|method| {
+            let signature = self.method_signature(method);
+            let method_name = format_ident!("{}", to_snake_case(&method.name));
+            let args = self.method_params(method).into_iter().map(|(name, _)| name);
+            quote! {
+                #signature {
+                    #struct_name::#method_name(self #(, #args)*).await
+                }
+            }
+        });
+
+        quote! {
+            #[async_trait]
+            impl #trait_path for #struct_name {
+                #root_getter
+                #(#getters)*
+                #(#methods)*
+            }
+        }
+    }
+
+    /// Public elements, which make up the page object's interface
+    fn public_elements(&self) -> Vec<ScopedElement<'_>> {
+        self.all_elements().into_iter().filter(|scoped| scoped.element.public).collect()
+    }
+
+    /// Signature of the root element getter, without visibility
+    ///
+    /// The root is wrapped according to the page object's root `type`.
+    fn root_getter_signature(&self) -> TokenStream {
+        let root_type = action_types_wrapper(&self.ast.action_types);
+        quote! { async fn get_root(&self) -> UtamResult<#root_type> }
+    }
+
+    /// Signature of an element getter, without visibility
+    fn getter_signature(&self, scoped: &ScopedElement) -> TokenStream {
+        let method_name = format_ident!("get_{}", to_snake_case(&scoped.element.name));
+        let params = self.getter_params(scoped);
+        let return_type = self.scoped_return_type(scoped);
+        let generics = is_container(scoped.element).then(|| quote! { <T: RootPageObject> });
+        quote! {
+            async fn #method_name #generics(&self #(, #params)*) -> UtamResult<#return_type>
+        }
+    }
+
+    /// Check every selector's strategy is available on the declared platform
+    ///
+    /// # Errors
+    ///
+    /// * `CompilerError::UnsupportedSelector` - For the first selector that is not
+    fn check_platform(&self) -> CompilerResult<()> {
+        let platform = self.ast.platform.as_deref();
+        let root = self.ast.selector.as_ref().map(|s| ("root", s));
+        let elements = self.all_elements();
+        let selectors = elements.iter().filter_map(|scoped| {
+            Some((scoped.element.name.as_str(), scoped.element.selector.as_ref()?))
+        });
+
+        for (element, selector) in root.into_iter().chain(selectors) {
+            let selector_type = selector.selector_type();
+            if !selector_type.supported_on(platform) {
+                return Err(CompilerError::UnsupportedSelector {
+                    element: element.to_string(),
+                    strategy: selector_type.strategy().to_string(),
+                    platform: platform.unwrap_or("web").to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check every list element's filter can be generated
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidFilter` for a filter without `apply`, with an
+    /// unsupported matcher, or whose element action returns no value or a
+    /// value of a type the matcher cannot test
+    fn check_filters(&self) -> CompilerResult<()> {
+        for scoped in self.all_elements() {
+            let element = scoped.element;
+            let Some(filter) = element.filter.as_ref().filter(|_| element.list) else {
+                continue;
+            };
+            let invalid = |message: String| CompilerError::InvalidFilter {
+                element: element.name.clone(),
+                message,
+            };
+
+            if filter.apply.is_none() {
+                return Err(invalid("filter has no apply method".to_string()));
+            }
+            let matcher = &filter.matcher;
+            if self.filter_condition(matcher).is_none() {
+                return Err(invalid(format!(
+                    "unsupported matcher '{}' with {} argument(s)",
+                    matcher.matcher_type,
+                    matcher.args.len()
+                )));
+            }
+
+            // Custom components' methods are declared elsewhere, so only
+            // basic element actions have a known type
+            let basic = matches!(element.element_type, None | Some(ElementTypeAst::ActionTypes(_)));
+            let apply = filter.apply.as_deref().unwrap_or_default();
+            let Some(value_type) = action_return_type(apply).filter(|_| basic) else {
+                continue;
+            };
+            if type_name(&value_type) == "()" {
+                return Err(invalid(format!("apply method '{}' returns no value to match", apply)));
+            }
+            if !matcher_accepts(&matcher.matcher_type, &value_type) {
+                return Err(invalid(format!(
+                    "matcher '{}' cannot test the {} returned by '{}'",
+                    matcher.matcher_type,
+                    type_name(&value_type),
+                    apply
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every element declared inside a frame can enter it
+    ///
+    /// The frame's getter must return a single `FrameElement`, and frames
+    /// cannot be nested.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidFrame` for the first element that cannot
+    fn check_frames(&self) -> CompilerResult<()> {
+        for scoped in self.all_elements() {
+            let Some(index) = scoped.frame() else { continue };
+            let invalid = |message: String| CompilerError::InvalidFrame {
+                element: scoped.element.name.clone(),
+                message,
+            };
+
+            let frame = scoped.ancestors[index].0;
+            if frame.list || frame.nullable {
+                return Err(invalid(format!(
+                    "is declared inside frame '{}', which is a list or nullable",
+                    frame.name
+                )));
+            }
+            let nested = scoped.ancestors[index + 1..]
+                .iter()
+                .find(|(ancestor, _)| matches!(ancestor.element_type, Some(ElementTypeAst::Frame)));
+            if let Some((inner, _)) = nested {
+                return Err(invalid(format!(
+                    "is declared inside frame '{}', which is nested in frame '{}'",
+                    inner.name, frame.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every container's getter can build a `Container`
+    ///
+    /// A container loads a single page object, found with CSS from the
+    /// element's search context.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidContainer` for the first container that cannot
+    fn check_containers(&self) -> CompilerResult<()> {
+        for scoped in self.all_elements() {
+            let element = scoped.element;
+            if !is_container(element) {
+                continue;
+            }
+            let invalid = |message: &str| CompilerError::InvalidContainer {
+                element: element.name.clone(),
+                message: message.to_string(),
+            };
+
+            if element.list {
+                return Err(invalid("is a list, but a container loads a single page object"));
+            }
+            if scoped.frame().is_some() {
+                return Err(invalid("is declared inside a frame"));
+            }
+            if !uses_css(element.selector.as_ref()) {
+                return Err(invalid("has a mobile selector, but containers find content with CSS"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every element marked `load` can be looked up when its
+    /// page object loads
+    ///
+    /// Only root page objects load, so nothing checks the elements of any
+    /// other page object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLoad` for the first element that cannot
+    fn check_loads(&self) -> CompilerResult<()> {
+        for scoped in self.all_elements() {
+            let element = scoped.element;
+            if !element.load {
+                continue;
+            }
+            let invalid = |message: &str| CompilerError::InvalidLoad {
+                element: element.name.clone(),
+                message: message.to_string(),
+            };
+
+            if !scoped.params().is_empty() {
+                return Err(invalid("its getter takes arguments"));
+            }
+            if scoped.frame().is_some() {
+                return Err(invalid("is declared inside a frame"));
+            }
+            if is_container(element) {
+                return Err(invalid("is a container, whose content is chosen by the caller"));
+            }
+            if !self.ast.root {
+                return Err(invalid("is declared in a page object that is not a root"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every chained compose statement follows a statement whose
+    /// result has the element or method being chained to
+    ///
+    /// Members of custom components are only checked when the symbol table
+    /// knows them.
+    fn check_chains(&self) -> CompilerResult<()> {
+        for method in &self.ast.methods {
+            self.check_chained_statements(&method.name, &method.compose)?;
+        }
+        self.check_chained_statements("beforeLoad", &self.ast.before_load)
+    }
+
+    /// Check the chained statements of one statement list and its predicates
+    fn check_chained_statements(
+        &self,
+        method: &str,
+        statements: &[ComposeStatementAst],
+    ) -> CompilerResult<()> {
+        for predicate in statements.iter().filter_map(|stmt| stmt.predicate.as_ref()) {
+            self.check_chained_statements(method, predicate)?;
+        }
+        for (i, stmt) in statements.iter().enumerate().filter(|(_, s)| s.chain) {
+            let invalid = |message: String| {
+                CompilerError::InvalidStatement(format!(
+                    "statement {} of method '{}' {}",
+                    i, method, message
+                ))
+            };
+            let Some(previous) = i.checked_sub(1).map(|p| &statements[p]) else {
+                return Err(invalid("is chained but has no previous statement".to_string()));
+            };
+            let Some(source) = self.chain_source(previous) else {
+                return Err(invalid("is chained to a statement without a returnType".to_string()));
+            };
+
+            match (source, &stmt.element, &stmt.apply) {
+                (_, None, None) => {
+                    return Err(invalid("is chained but has no element or apply".to_string()));
+                }
+                (ChainSource::Value(value_type), _, _) => {
+                    return Err(invalid(format!("is chained to a '{}' value", value_type)));
+                }
+                (ChainSource::Element(_), Some(element), _) => {
+                    return Err(invalid(format!(
+                        "chains element '{}' to a basic element",
+                        element
+                    )));
+                }
+                (ChainSource::Element(types), None, Some(apply)) => {
+                    if !element_actions(&types).contains(&apply.as_str()) {
+                        let kind =
+                            if types.is_empty() { "basic".to_string() } else { types.join("/") };
+                        return Err(invalid(format!(
+                            "chains '{}', which is not an action of a {} element",
+                            apply, kind
+                        )));
+                    }
+                }
+                (ChainSource::Component(type_path), element, apply) => {
+                    let members = self
+                        .config
+                        .symbols
+                        .as_ref()
+                        .and_then(|symbols| symbols.members(&type_path));
+                    let Some(members) = members else { continue };
+                    if let Some(element) = element {
+                        if !members.elements.contains(element) {
+                            return Err(invalid(format!(
+                                "chains element '{}', which '{}' does not expose",
+                                element, type_path
+                            )));
+                        }
+                    } else if let Some(apply) = apply {
+                        if !members.methods.contains(apply) {
+                            return Err(invalid(format!(
+                                "chains method '{}', which '{}' does not declare",
+                                apply, type_path
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check no compose method has the name of an element getter
+    ///
+    /// # Errors
+    ///
+    /// Returns `NameCollision` for the first method named like a getter,
+    /// such as a method `getTotal` next to an element `total`
+    fn check_method_names(&self) -> CompilerResult<()> {
+        let elements = self.all_elements();
+        for method in &self.ast.methods {
+            let name = to_snake_case(&method.name);
+            let getter = elements
+                .iter()
+                .find(|scoped| format!("get_{}", to_snake_case(&scoped.element.name)) == name);
+            if let Some(scoped) = getter {
+                return Err(CompilerError::NameCollision {
+                    method: method.name.clone(),
+                    element: scoped.element.name.clone(),
+                    name,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every compose statement with a matcher has a value to
+    /// match, a supported matcher, and, where the value's type is known, a
+    /// value of a type the matcher can test
+    fn check_matchers(&self) -> CompilerResult<()> {
+        let methods = self.ast.methods.iter().map(|m| (m.name.as_str(), &m.compose));
+        let before_load = std::iter::once(("beforeLoad", &self.ast.before_load));
+        for (method, statements) in methods.chain(before_load) {
+            self.check_statement_matchers(method, statements)?;
+        }
+        Ok(())
+    }
+
+    /// Check the matchers of a list of compose statements and, recursively,
+    /// of their predicates
+    fn check_statement_matchers(
+        &self,
+        method: &str,
+        statements: &[ComposeStatementAst],
+    ) -> CompilerResult<()> {
+        for (i, stmt) in statements.iter().enumerate() {
+            if let Some(predicate) = &stmt.predicate {
+                self.check_statement_matchers(method, predicate)?;
+            }
+            let Some(matcher) = &stmt.matcher else { continue };
+            if stmt.element.is_none() && stmt.apply.is_none() && stmt.predicate.is_none() {
+                return Err(CompilerError::InvalidStatement(format!(
+                    "matcher '{}' in method '{}' has no element or apply to match",
+                    matcher.matcher_type, method
+                )));
+            }
+            if self.filter_condition(matcher).is_none() {
+                return Err(CompilerError::InvalidStatement(format!(
+                    "unsupported matcher '{}' with {} argument(s) in method '{}'",
+                    matcher.matcher_type,
+                    matcher.args.len(),
+                    method
+                )));
+            }
+            let value_type = self.statement_type(&statements[..=i]);
+            if let Some(value_type) =
+                value_type.filter(|value_type| !matcher_accepts(&matcher.matcher_type, value_type))
+            {
+                return Err(CompilerError::InvalidStatement(format!(
+                    "matcher '{}' in method '{}' cannot test a value of type {}",
+                    matcher.matcher_type,
+                    method,
+                    type_name(&value_type)
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every declared method return type matches the type
+    /// inferred from the method's last statement, and that a method ending
+    /// in a chained statement of unknown type declares one
+    fn check_return_types(&self) -> CompilerResult<()> {
+        for method in &self.ast.methods {
+            if method.return_type.is_none()
+                && !method.compose.iter().any(|s| s.return_element)
+                && ends_in_chain(&method.compose)
+                && self.statement_type(&method.compose).is_none()
+            {
+                return Err(CompilerError::UnknownReturnType { method: method.name.clone() });
+            }
+            let (Some(declared), Some(inferred)) =
+                (self.declared_return_type(method), self.inferred_return_type(method))
+            else {
+                continue;
+            };
+            if type_name(&declared) != type_name(&inferred) {
+                return Err(CompilerError::ReturnTypeMismatch {
+                    method: method.name.clone(),
+                    declared: type_name(&declared),
+                    inferred: type_name(&inferred),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that `document` is only used with document actions, and that
+    /// `enterFrameAndLoad` names the page object to load
+    fn check_document(&self) -> CompilerResult<()> {
+        let methods = self
+            .ast
+            .methods
+            .iter()
+            .map(|method| (format!("method '{}'", method.name), &method.compose));
+        let before_load = std::iter::once(("beforeLoad".to_string(), &self.ast.before_load));
+        for (owner, statements) in methods.chain(before_load) {
+            for stmt in with_predicates(statements) {
+                let (Some("document"), Some(apply)) = (stmt.element.as_deref(), &stmt.apply) else {
+                    continue;
+                };
+                if self.document_action_type(apply, &stmt.args).is_some() {
+                    continue;
+                }
+                let message = if apply == "enterFrameAndLoad" {
+                    format!("{} applies enterFrameAndLoad without a rootPageObject argument", owner)
+                } else {
+                    format!(
+                        "{} applies '{}' to document, which is not a document action",
+                        owner, apply
+                    )
+                };
+                return Err(CompilerError::InvalidStatement(message));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rust type a `document` action returns, or `None` for an unknown
+    /// action
+    ///
+    /// `enterFrameAndLoad` returns the page object named by its
+    /// `rootPageObject` argument.
+    fn document_action_type(&self, action: &str, args: &[ComposeArgAst]) -> Option<TokenStream> {
+        match action {
+            "getUrl" => Some(quote! { String }),
+            "waitForDocumentReady" => Some(quote! { () }),
+            "containsElement" => Some(quote! { bool }),
+            "enterFrameAndLoad" => {
+                page_object_arg(args).map(|path| self.custom_component_type(path))
+            }
+            _ => None,
+        }
+    }
+
+    /// What a compose statement's result can be chained to, or `None` if
+    /// its type is unknown
+    ///
+    /// An explicit `returnType` wins. Otherwise only a statement that just
+    /// gets one of this page object's elements has a known type.
+    fn chain_source(&self, stmt: &ComposeStatementAst) -> Option<ChainSource> {
+        if let Some(return_type) = &stmt.return_type {
+            return Some(match return_type.as_str() {
+                "actionable" | "clickable" | "editable" | "draggable" | "touchable" => {
+                    ChainSource::Element(vec![return_type.clone()])
+                }
+                path if path.contains('/') => ChainSource::Component(path.to_string()),
+                other => ChainSource::Value(other.to_string()),
+            });
+        }
+        if stmt.chain || stmt.apply.is_some() {
+            return None;
+        }
+        let scoped = self.find_element(stmt.element.as_ref()?)?;
+        if scoped.element.nullable || returns_vec(scoped.element) {
+            return None;
+        }
+        match &scoped.element.element_type {
+            None => Some(ChainSource::Element(Vec::new())),
+            Some(ElementTypeAst::ActionTypes(types)) => Some(ChainSource::Element(types.clone())),
+            Some(ElementTypeAst::CustomComponent(path)) => {
+                Some(ChainSource::Component(path.clone()))
+            }
+            Some(_) => None,
+        }
+    }
+
     /// Get the struct name from module name or default
     fn struct_name(&self) -> String {
         self.config
@@ -451,10 +1408,13 @@ impl CodeGenerator {
                 quote! { #[doc = #text] }
             }
             Some(DescriptionAst::Detailed { text, author, .. }) => {
-                let doc_lines: Vec<_> = text.iter().map(|line| {
-                    quote! { #[doc = #line] }
-                }).collect();
-                
+                let doc_lines: Vec<_> = text
+                    .iter()
+                    .map(|line| {
+                        quote! { #[doc = #line] }
+                    })
+                    .collect();
+
                 let author_doc = if let Some(auth) = author {
                     let author_line = format!("\nAuthor: {}", auth);
                     quote! { #[doc = #author_line] }
@@ -483,52 +1443,169 @@ impl CodeGenerator {
     }
 
     /// Generate RootPageObject trait implementation
+    ///
+    /// Loading runs the `beforeLoad` statements, finds the root element and
+    /// checks the elements marked `load`, retrying until all succeed or the
+    /// wait times out. `load` waits for the configured wait timeout, or the
+    /// default one.
     fn generate_root_page_object_impl(&self, struct_name: &proc_macro2::Ident) -> TokenStream {
-        let selector = self.ast.selector.as_ref()
-            .and_then(|s| s.css.as_ref())
-            .map(|s| s.as_str())
-            .unwrap_or("");
+        let root_selector = self.ast.selector.as_ref();
+        let selector_type = root_selector.map(|s| s.selector_type());
+        let selector = selector_type.as_ref().map(|t| t.value()).unwrap_or("");
+        let lookup = self.root_lookup();
+
+        let has_before_load = !self.ast.before_load.is_empty();
+        let before_load = has_before_load.then(|| quote! { Self::before_load(driver).await?; });
+        let timeout = self.default_timeout();
+        let wait_config = self.wait_config();
+
+        let before_load_fn = has_before_load.then(|| {
+            let body = self.generate_before_load_body();
+            let uses_driver =
+                uses_document(&self.ast.before_load) || uses_element(&self.ast.before_load, "root");
+            let driver =
+                if uses_driver { format_ident!("driver") } else { format_ident!("_driver") };
+            quote! {
+                /// Execute beforeLoad conditions
+                async fn before_load(#driver: &WebDriver) -> UtamResult<()> {
+                    #body
+                }
+            }
+        });
 
-        let before_load_body = if !self.ast.before_load.is_empty() {
-            self.generate_before_load_body()
+        let has_load_elements = self.all_elements().iter().any(|scoped| scoped.element.load);
+        let load_elements_fn = has_load_elements.then(|| {
+            let body = self.generate_load_elements_body(struct_name);
+            quote! {
+                /// Check the elements marked `load` are present
+                async fn load_elements(&self) -> UtamResult<()> {
+                    #body
+                }
+            }
+        });
+        let from_element_body = if has_load_elements {
+            quote! {
+                let page = Self { root: element };
+                page.load_elements().await?;
+                Ok(page)
+            }
         } else {
-            quote! { Ok(()) }
+            quote! { Ok(Self { root: element }) }
         };
 
+        let helpers_impl = (has_before_load || has_load_elements).then(|| {
+            quote! {
+                impl #struct_name {
+                    #before_load_fn
+                    #load_elements_fn
+                }
+            }
+        });
+
         quote! {
             #[async_trait::async_trait]
             impl RootPageObject for #struct_name {
                 const ROOT_SELECTOR: &'static str = #selector;
 
                 async fn load(driver: &WebDriver) -> UtamResult<Self> {
-                    // Execute beforeLoad if defined
-                    Self::before_load(driver).await?;
+                    Self::wait_for_load(driver, #timeout).await
+                }
 
-                    let root = driver.find(By::Css(Self::ROOT_SELECTOR)).await?;
-                    Self::from_element(root).await
+                async fn wait_for_load(
+                    driver: &WebDriver,
+                    timeout: std::time::Duration,
+                ) -> UtamResult<Self> {
+                    let config = #wait_config;
+                    wait_for(
+                        || async {
+                            #before_load
+                            let root = #lookup.await?;
+                            Ok(Some(Self::from_element(root).await?))
+                        },
+                        &config,
+                        "page object to load",
+                    )
+                    .await
                 }
 
                 async fn from_element(element: WebElement) -> UtamResult<Self> {
-                    Ok(Self { root: element })
+                    #from_element_body
                 }
             }
 
-            impl #struct_name {
-                /// Execute beforeLoad conditions
-                async fn before_load(driver: &WebDriver) -> UtamResult<()> {
-                    #before_load_body
+            #helpers_impl
+        }
+    }
+
+    /// Generate the body checking the elements marked `load` are present
+    ///
+    /// Each element is looked up with its getter; a failed lookup, a
+    /// missing nullable element or an empty list is `ElementNotFound`
+    /// naming the page object and the element.
+    fn generate_load_elements_body(&self, struct_name: &proc_macro2::Ident) -> TokenStream {
+        let elements = self.all_elements();
+        let loaded = elements.iter().map(|scoped| scoped.element).filter(|element| element.load);
+        let checks = loaded.map(|element| {
+            let getter_name = format_ident!("get_{}", to_snake_case(&element.name));
+            let lookup = quote! { self.#getter_name().await };
+            let missing = if returns_vec(element) {
+                quote! { !#lookup.is_ok_and(|elements| !elements.is_empty()) }
+            } else if element.nullable {
+                quote! { !#lookup.is_ok_and(|element| element.is_some()) }
+            } else {
+                quote! { #lookup.is_err() }
+            };
+            let name = format!("{}.{}", struct_name, element.name);
+            let selector = element
+                .selector
+                .as_ref()
+                .map(|selector| selector.selector_type().value().to_string())
+                .unwrap_or_default();
+            quote! {
+                if #missing {
+                    return Err(UtamError::ElementNotFound {
+                        name: #name.to_string(),
+                        selector: #selector.to_string(),
+                    });
                 }
             }
+        });
+
+        quote! {
+            #(#checks)*
+            Ok(())
+        }
+    }
+
+    /// Expression finding the root element through `driver`
+    fn root_lookup(&self) -> TokenStream {
+        match self.ast.selector.as_ref() {
+            Some(root_selector) if !uses_css(Some(root_selector)) => {
+                let by = generate_selector_code(root_selector);
+                quote! { driver.locate(&#by) }
+            }
+            _ => quote! { driver.find(By::Css(Self::ROOT_SELECTOR)) },
         }
     }
 
     /// Generate beforeLoad method body
+    ///
+    /// The statements are compiled like a method's; `document` is the
+    /// driver's current page, and `root` is looked up each time it is used.
     fn generate_before_load_body(&self) -> TokenStream {
-        let statements: Vec<_> = self.ast.before_load.iter().map(|stmt| {
-            self.generate_compose_statement(stmt, None)
-        }).collect();
+        let generator =
+            Self { ast: self.ast.clone(), config: self.config.clone(), before_load: true };
+        let statements = generator.generate_compose_statements(&self.ast.before_load, false, false);
+        let document = uses_document(&self.ast.before_load)
+            .then(|| quote! { let document = Document::new(driver); });
+        let root = uses_element(&self.ast.before_load, "root").then(|| {
+            let lookup = self.root_lookup();
+            quote! { let root = || #lookup; }
+        });
 
         quote! {
+            #document
+            #root
             #(#statements)*
             Ok(())
         }
@@ -538,66 +1615,145 @@ impl CodeGenerator {
     fn generate_element_getters(&self, _struct_name: &proc_macro2::Ident) -> TokenStream {
         let mut getters = Vec::new();
 
-        // Get all elements including shadow elements
-        for element in self.all_elements() {
-            getters.push(self.generate_element_getter(&element));
+        // With exposeRootElement, the root is public like any element
+        if self.ast.expose_root_element {
+            getters.push(self.generate_root_getter());
+        }
+
+        // Get all elements including shadow and nested elements
+        for scoped in self.all_elements() {
+            getters.push(self.generate_element_getter(&scoped));
+
+            // A container also exposes the `Container` its getter loads from
+            if is_container(scoped.element) {
+                getters.push(self.generate_container_getter(&scoped));
+            }
 
             // If wait is true, generate a wait method
-            if element.generate_wait {
-                getters.push(self.generate_wait_method(&element));
+            if scoped.element.generate_wait {
+                getters.push(self.generate_wait_method(&scoped));
             }
         }
 
         quote! { #(#getters)* }
     }
 
-    /// Get all elements including shadow elements
-    fn all_elements(&self) -> Vec<&ElementAst> {
-        let mut elements = Vec::new();
-        
-        // Add regular elements
-        for elem in &self.ast.elements {
-            elements.push(elem);
+    /// Check no two elements, in any scope, share a getter name
+    ///
+    /// # Errors
+    ///
+    /// Returns `DuplicateElement` for the first element whose getter is
+    /// already generated for an element declared earlier
+    fn check_element_names(&self) -> CompilerResult<()> {
+        let mut getters = BTreeMap::new();
+        for scoped in self.all_elements() {
+            let name = format!("get_{}", to_snake_case(&scoped.element.name));
+            if let Some(first) = getters.insert(name.clone(), &scoped.element.name) {
+                return Err(CompilerError::DuplicateElement {
+                    first: first.clone(),
+                    second: scoped.element.name.clone(),
+                    name,
+                });
+            }
         }
-        
-        // Add shadow elements
-        if let Some(shadow) = &self.ast.shadow {
-            for elem in &shadow.elements {
-                elements.push(elem);
+        Ok(())
+    }
+
+    /// Check no element getter takes two parameters with the same name
+    ///
+    /// # Errors
+    ///
+    /// Returns `DuplicateParameter` when a nested element's selector arg is
+    /// named like one of an enclosing element's, a filter arg like a
+    /// selector arg, or a waited-for element has an arg named `timeout`
+    fn check_getter_params(&self) -> CompilerResult<()> {
+        for scoped in self.all_elements() {
+            let mut names = std::collections::BTreeSet::new();
+            if scoped.element.generate_wait {
+                names.insert("timeout".to_string());
+            }
+            for (name, _) in scoped.params() {
+                let name = to_snake_case(name);
+                if !names.insert(name.clone()) {
+                    return Err(CompilerError::DuplicateParameter {
+                        element: scoped.element.name.clone(),
+                        name,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate the public getter of the root element
+    fn generate_root_getter(&self) -> TokenStream {
+        let signature = self.root_getter_signature();
+        let root_type = action_types_constructor(&self.ast.action_types);
+        quote! {
+            #[doc = "Get the root element"]
+            pub #signature {
+                Ok(#root_type::new(self.root.clone()))
             }
         }
-        
+    }
+
+    /// Get all elements including shadow and nested elements
+    fn all_elements(&self) -> Vec<ScopedElement<'_>> {
+        let mut elements = Vec::new();
+        collect_scoped(&self.ast.elements, &[], false, &mut elements);
+        if let Some(shadow) = &self.ast.shadow {
+            collect_scoped(&shadow.elements, &[], true, &mut elements);
+        }
         elements
     }
 
     /// Generate a single element getter
-    fn generate_element_getter(&self, element: &ElementAst) -> TokenStream {
-        let method_name = format_ident!("get_{}", to_snake_case(&element.name));
+    fn generate_element_getter(&self, scoped: &ScopedElement) -> TokenStream {
+        let element = scoped.element;
         let visibility = if element.public {
             quote! { pub }
         } else {
             quote! {}
         };
 
-        let return_type = self.element_return_type(element);
-        let body = self.generate_element_body(element);
-        let doc = if let Some(desc) = &element.description {
-            quote! { #[doc = #desc] }
-        } else {
-            let doc_text = format!("Get the {} element", element.name);
-            quote! { #[doc = #doc_text] }
-        };
+        let signature = self.getter_signature(scoped);
+        let body = self.generate_element_body(scoped);
+        let doc = self.getter_doc(element);
 
         quote! {
             #doc
-            #visibility async fn #method_name(&self) -> UtamResult<#return_type> {
+            #visibility #signature {
                 #body
             }
         }
     }
 
+    /// Doc comment of an element getter
+    fn getter_doc(&self, element: &ElementAst) -> TokenStream {
+        if let Some(desc) = &element.description {
+            quote! { #[doc = #desc] }
+        } else {
+            let doc_text = format!("Get the {} element", element.name);
+            quote! { #[doc = #doc_text] }
+        }
+    }
+
+    /// Parameters of an element getter, one per selector or filter argument
+    fn getter_params(&self, scoped: &ScopedElement) -> Vec<TokenStream> {
+        scoped
+            .params()
+            .into_iter()
+            .map(|(name, arg_type)| {
+                let name = format_ident!("{}", to_snake_case(name));
+                let arg_type = self.rust_type_from_string(arg_type);
+                quote! { #name: #arg_type }
+            })
+            .collect()
+    }
+
     /// Generate wait method for an element
-    fn generate_wait_method(&self, element: &ElementAst) -> TokenStream {
+    fn generate_wait_method(&self, scoped: &ScopedElement) -> TokenStream {
+        let element = scoped.element;
         let method_name = format_ident!("wait_for_{}", to_snake_case(&element.name));
         let getter_name = format_ident!("get_{}", to_snake_case(&element.name));
         let visibility = if element.public {
@@ -607,16 +1763,53 @@ impl CodeGenerator {
         };
 
         let doc = format!("Wait for the {} element to be available", element.name);
+        let (generics, turbofish) = if is_container(element) {
+            (Some(quote! { <T: RootPageObject> }), Some(quote! { ::<T> }))
+        } else {
+            (None, None)
+        };
+        let wait_config = self.wait_config();
+        // A nullable getter succeeds with `None` while the element is missing
+        let optional = element.nullable && !returns_vec(element);
+        let found = match (scoped.frame(), optional) {
+            // Leave the frame before the next poll enters it again
+            (Some(_), _) => {
+                let present = if optional {
+                    quote! { framed.value().is_some() }
+                } else {
+                    quote! { true }
+                };
+                quote! {
+                    Ok(framed) => {
+                        let present = #present;
+                        framed.exit().await?;
+                        Ok(present.then_some(()))
+                    }
+                }
+            }
+            (None, true) => quote! { Ok(Some(_)) => Ok(Some(())), },
+            (None, false) => quote! { Ok(_) => Ok(Some(())), },
+        };
+        let params = self.getter_params(scoped);
+        let args: Vec<_> = scoped
+            .params()
+            .into_iter()
+            .map(|(name, _)| format_ident!("{}", to_snake_case(name)))
+            .collect();
 
         quote! {
             #[doc = #doc]
-            #visibility async fn #method_name(&self, timeout: std::time::Duration) -> UtamResult<()> {
-                let config = WaitConfig { timeout, ..Default::default() };
+            #visibility async fn #method_name #generics(
+                &self,
+                #(#params,)*
+                timeout: std::time::Duration,
+            ) -> UtamResult<()> {
+                let config = #wait_config;
                 wait_for(
                     || async {
-                        match self.#getter_name().await {
-                            Ok(_) => Ok(Some(())),
-                            Err(_) => Ok(None),
+                        match self.#getter_name #turbofish(#(#args),*).await {
+                            #found
+                            _ => Ok(None),
                         }
                     },
                     &config,
@@ -627,45 +1820,102 @@ impl CodeGenerator {
         }
     }
 
+    /// Expression for the configured `WaitConfig`, defaulting what is not
+    /// configured
+    fn configured_wait_config(&self) -> TokenStream {
+        let timeout = self.config.wait_timeout.map(|timeout| {
+            let millis = timeout.as_millis() as u64;
+            quote! { timeout: std::time::Duration::from_millis(#millis), }
+        });
+        let poll_interval = self.config.poll_interval.map(|interval| {
+            let millis = interval.as_millis() as u64;
+            quote! { poll_interval: std::time::Duration::from_millis(#millis), }
+        });
+        match (&timeout, &poll_interval) {
+            (None, None) => quote! { WaitConfig::default() },
+            (Some(_), Some(_)) => quote! { WaitConfig { #timeout #poll_interval } },
+            _ => quote! { WaitConfig { #timeout #poll_interval ..Default::default() } },
+        }
+    }
+
+    /// Expression for the configured wait timeout, or the default one
+    fn default_timeout(&self) -> TokenStream {
+        match self.config.wait_timeout {
+            Some(timeout) => {
+                let millis = timeout.as_millis() as u64;
+                quote! { std::time::Duration::from_millis(#millis) }
+            }
+            None => quote! { WaitConfig::default().timeout },
+        }
+    }
+
+    /// Generate a `WaitConfig` expression using a local `timeout` binding
+    ///
+    /// Uses the configured poll interval, or `WaitConfig`'s default.
+    fn wait_config(&self) -> TokenStream {
+        match self.config.poll_interval {
+            Some(interval) => {
+                let millis = interval.as_millis() as u64;
+                quote! {
+                    WaitConfig {
+                        timeout,
+                        poll_interval: std::time::Duration::from_millis(#millis),
+                    }
+                }
+            }
+            None => quote! { WaitConfig { timeout, ..Default::default() } },
+        }
+    }
+
+    /// Return type of an element's getter
+    ///
+    /// An element declared inside a frame is returned with the frame's
+    /// context.
+    fn scoped_return_type(&self, scoped: &ScopedElement) -> TokenStream {
+        let return_type = self.element_return_type(scoped.element);
+        match scoped.frame() {
+            Some(_) => quote! { Framed<#return_type> },
+            None => return_type,
+        }
+    }
+
     /// Determine element return type
+    ///
+    /// Lists are returned as an `ElementList`. Nullable single elements are
+    /// optional; a missing nullable list is returned empty.
     fn element_return_type(&self, element: &ElementAst) -> TokenStream {
-        if element.list {
-            let inner_type = self.element_single_type(element);
-            quote! { Vec<#inner_type> }
+        let inner_type = self.element_single_type(element);
+        if returns_vec(element) {
+            quote! { ElementList<#inner_type> }
+        } else if element.nullable {
+            quote! { Option<#inner_type> }
         } else {
-            self.element_single_type(element)
+            inner_type
         }
     }
 
+    /// Find an element by name anywhere in the page object
+    fn find_element(&self, name: &str) -> Option<ScopedElement<'_>> {
+        self.all_elements().into_iter().find(|scoped| scoped.element.name == name)
+    }
+
+    /// Whether the getter for the named element returns an `Option`, or a
+    /// `Framed` one inside a frame
+    fn is_optional_element(&self, name: &str) -> bool {
+        self.find_element(name)
+            .is_some_and(|scoped| scoped.element.nullable && !returns_vec(scoped.element))
+    }
+
     /// Determine single element type
     fn element_single_type(&self, element: &ElementAst) -> TokenStream {
         match &element.element_type {
-            Some(ElementTypeAst::ActionTypes(types)) => {
-                // Determine which element wrapper to use based on action types
-                if types.iter().any(|t| t == "draggable") {
-                    quote! { DraggableElement }
-                } else if types.iter().any(|t| t == "editable") {
-                    quote! { EditableElement }
-                } else if types.iter().any(|t| t == "clickable") {
-                    quote! { ClickableElement }
-                } else if types.iter().any(|t| t == "actionable") {
-                    quote! { BaseElement }
-                } else {
-                    quote! { BaseElement }
-                }
-            }
-            Some(ElementTypeAst::CustomComponent(path)) => {
-                // Convert path like "package/pageObjects/component" to PascalCase
-                let component_name = path.split('/').last().unwrap_or(path);
-                let ident = format_ident!("{}", to_pascal_case(component_name));
-                quote! { #ident }
-            }
+            Some(ElementTypeAst::ActionTypes(types)) => action_types_wrapper(types),
+            Some(ElementTypeAst::CustomComponent(path)) => self.custom_component_type(path),
             Some(ElementTypeAst::Container) => {
-                quote! { ContainerElement }
+                quote! { T }
             }
             Some(ElementTypeAst::Frame) => {
-                // Frames return WebElement directly
-                quote! { WebElement }
+                quote! { FrameElement }
             }
             None => {
                 quote! { BaseElement }
@@ -673,68 +1923,416 @@ impl CodeGenerator {
         }
     }
 
+    /// Resolve a custom component type path to a Rust type
+    ///
+    /// Components in the project's symbol table are referenced by their
+    /// fully-qualified path, components from packages listed in
+    /// `package_modules` through the mapped module, and others by their
+    /// bare struct name.
+    fn custom_component_type(&self, path: &str) -> TokenStream {
+        let resolved = self.config.symbols.as_ref().and_then(|symbols| symbols.resolve(path));
+        if let Some(rust_path) = resolved.and_then(|p| syn::parse_str::<syn::Path>(p).ok()) {
+            return quote! { #rust_path };
+        }
+
+        let component = CustomComponentRef::parse(path);
+        let ident = format_ident!("{}", to_pascal_case(&component.name));
+
+        match self.config.package_modules.get(&component.package) {
+            Some(module) => {
+                let segments = module
+                    .split("::")
+                    .map(str::to_string)
+                    .chain(component.path.iter().map(|s| module_ident(s)))
+                    .map(|s| format_ident!("{}", s));
+                quote! { #(#segments::)*#ident }
+            }
+            None => quote! { #ident },
+        }
+    }
+
     /// Generate element getter body
-    fn generate_element_body(&self, element: &ElementAst) -> TokenStream {
-        let selector = element.selector.as_ref()
-            .and_then(|s| s.css.as_ref())
-            .map(|s| s.as_str())
-            .unwrap_or("");
-
-        let is_shadow = self.is_shadow_element(element);
-        
-        if element.list {
-            // List of elements
-            let wrapper_code = self.generate_element_wrapper(element);
-            
-            if is_shadow {
-                quote! {
-                    let shadow = self.root.get_shadow_root().await?;
-                    let elements = shadow.find_all(By::Css(#selector)).await?;
-                    let mut result = Vec::new();
-                    for elem in elements {
-                        #wrapper_code
-                        result.push(wrapped);
-                    }
-                    Ok(result)
+    ///
+    /// An element declared inside a frame is looked up after entering the
+    /// frame, and returned with the frame's context.
+    fn generate_element_body(&self, scoped: &ScopedElement) -> TokenStream {
+        if is_container(scoped.element) {
+            return self.generate_container_load_body(scoped);
+        }
+        let Some(index) = scoped.frame() else {
+            if returns_vec(scoped.element) {
+                return self.generate_list_query(scoped);
+            }
+            return self.generate_lookup_body(scoped, quote! { self.root });
+        };
+
+        // Enter the frame, then look the element up in the frame's document
+        let (frame, in_shadow) = scoped.ancestors[index];
+        let frame_scoped = ScopedElement {
+            element: frame,
+            ancestors: scoped.ancestors[..index].to_vec(),
+            in_shadow,
+        };
+        let frame_getter = format_ident!("get_{}", to_snake_case(&frame.name));
+        let frame_args = scoped
+            .params()
+            .into_iter()
+            .take(frame_scoped.params().len())
+            .map(|(name, _)| format_ident!("{}", to_snake_case(name)));
+        let inner = ScopedElement {
+            element: scoped.element,
+            ancestors: scoped.ancestors[index + 1..].to_vec(),
+            in_shadow: scoped.in_shadow,
+        };
+        let lookup = self.generate_lookup_body(&inner, quote! { context });
+        // The frame's context cannot be re-entered, so its lists are fixed
+        let value = if returns_vec(scoped.element) {
+            let inner_type = self.element_single_type(scoped.element);
+            quote! {
+                let items: UtamResult<Vec<#inner_type>> = async { #lookup }.await;
+                let value = items.map(ElementList::new);
+            }
+        } else {
+            let return_type = self.element_return_type(scoped.element);
+            quote! { let value: UtamResult<#return_type> = async { #lookup }.await; }
+        };
+
+        quote! {
+            let frame = self.#frame_getter(#(#frame_args),*).await?;
+            let context = frame.enter().await?;
+            #value
+            match value {
+                Ok(value) => Ok(Framed::new(value, context)),
+                Err(error) => {
+                    context.exit().await?;
+                    Err(error)
                 }
-            } else {
-                quote! {
-                    let elements = self.root.find_all(By::Css(#selector)).await?;
-                    let mut result = Vec::new();
-                    for elem in elements {
-                        #wrapper_code
-                        result.push(wrapped);
-                    }
-                    Ok(result)
+            }
+        }
+    }
+
+    /// Generate the body of a list element's getter, keeping the lookup as
+    /// the `ElementList`'s query
+    ///
+    /// The query outlives `&self`, so it captures a clone of the root and
+    /// owned copies of the getter's string arguments.
+    fn generate_list_query(&self, scoped: &ScopedElement) -> TokenStream {
+        let lookup = self.generate_lookup_body(scoped, quote! { root });
+        let strings: Vec<_> = scoped
+            .params()
+            .into_iter()
+            .filter(|(_, arg_type)| *arg_type == "string")
+            .map(|(name, _)| format_ident!("{}", to_snake_case(name)))
+            .collect();
+
+        quote! {
+            let root = self.root.clone();
+            #(let #strings = #strings.to_owned();)*
+            ElementList::query(move || {
+                let root = root.clone();
+                #(let #strings = #strings.clone();)*
+                async move {
+                    #(let #strings = #strings.as_str();)*
+                    #lookup
+                }
+            })
+            .await
+        }
+    }
+
+    /// Generate the body of a container's getter, loading the page object
+    /// the container holds
+    ///
+    /// A nullable container is `None` when an enclosing element is missing.
+    fn generate_container_load_body(&self, scoped: &ScopedElement) -> TokenStream {
+        let container_getter =
+            format_ident!("get_{}_container", to_snake_case(&scoped.element.name));
+        let args: Vec<_> = scoped
+            .params()
+            .into_iter()
+            .map(|(name, _)| format_ident!("{}", to_snake_case(name)))
+            .collect();
+        if scoped.element.nullable {
+            quote! {
+                match self.#container_getter::<T>(#(#args),*).await? {
+                    Some(container) => Ok(Some(container.load().await?)),
+                    None => Ok(None),
                 }
             }
         } else {
-            // Single element
-            let wrapper_code = self.generate_element_wrapper(element);
-            
-            if is_shadow {
-                quote! {
-                    let shadow = self.root.get_shadow_root().await?;
-                    let elem = shadow.find(By::Css(#selector)).await?;
+            quote! { self.#container_getter::<T>(#(#args),*).await?.load().await }
+        }
+    }
+
+    /// Generate the getter returning a container's `Container`
+    ///
+    /// The container searches the element's enclosing scope with the
+    /// element's selector, or with `Container`'s default
+    /// `:scope > *:first-child` when none is declared.
+    fn generate_container_getter(&self, scoped: &ScopedElement) -> TokenStream {
+        let element = scoped.element;
+        let visibility = if element.public {
+            quote! { pub }
+        } else {
+            quote! {}
+        };
+        let method_name = format_ident!("get_{}_container", to_snake_case(&element.name));
+        let doc = format!("Get the {} container", element.name);
+        let params = self.getter_params(scoped);
+        // Only the container's own absence makes a nullable container `None`
+        let (scope_setup, scope) = self.element_scope(scoped, quote! { self.root }, false);
+        let root = if scoped.ancestors.is_empty() && !scoped.in_shadow {
+            quote! { #scope.clone() }
+        } else {
+            scope
+        };
+        let container = match &element.selector {
+            Some(selector) if selector.has_parameters() => {
+                let by = generate_selector_code(selector);
+                quote! { Container::new(#root).with_selector(#by) }
+            }
+            Some(_) => {
+                let css = css_selector(element);
+                quote! { Container::new(#root).with_selector(By::Css(#css)) }
+            }
+            None => quote! { Container::new(#root) },
+        };
+        let (return_type, body) = if element.nullable {
+            let body = quote! {
+                let container = #container;
+                if !container.is_present().await? {
+                    return Ok(None);
+                }
+                Ok(Some(container))
+            };
+            (quote! { Option<Container<T>> }, body)
+        } else {
+            (quote! { Container<T> }, quote! { Ok(#container) })
+        };
+
+        quote! {
+            #[doc = #doc]
+            #visibility async fn #method_name<T: PageObject>(
+                &self #(, #params)*
+            ) -> UtamResult<#return_type> {
+                #scope_setup
+                #body
+            }
+        }
+    }
+
+    /// Generate the statements looking an element up from `root`
+    fn generate_lookup_body(&self, scoped: &ScopedElement, root: TokenStream) -> TokenStream {
+        let element = scoped.element;
+        let (scope_setup, scope) = self.element_scope(scoped, root, scoped.element.nullable);
+        let find = self.find_call(&scope, element, false);
+        let find_all = self.find_call(&scope, element, true);
+        let wrapper_code = self.generate_element_wrapper(element);
+
+        if let Some(filter) = element.filter.as_ref().filter(|_| element.list) {
+            self.generate_filtered_body(element, filter, scope_setup, find_all, wrapper_code)
+        } else if element.list {
+            quote! {
+                #scope_setup
+                let elements = #find_all.await?;
+                let mut result = Vec::new();
+                for elem in elements {
                     #wrapper_code
-                    Ok(wrapped)
+                    result.push(wrapped);
                 }
-            } else {
-                quote! {
-                    let elem = self.root.find(By::Css(#selector)).await?;
+                Ok(result)
+            }
+        } else if element.nullable {
+            quote! {
+                #scope_setup
+                let Some(elem) = #find_all.await?.into_iter().next() else {
+                    return Ok(None);
+                };
+                #wrapper_code
+                Ok(Some(wrapped))
+            }
+        } else {
+            quote! {
+                #scope_setup
+                let elem = #find.await?;
+                #wrapper_code
+                Ok(wrapped)
+            }
+        }
+    }
+
+    /// Generate the body of a list element getter with a filter
+    ///
+    /// Each candidate is wrapped, `apply` is called on it and the result
+    /// bound to `value` for the matcher. With `findFirst` the first match is
+    /// returned, and no match is `None` for a nullable element or
+    /// `ElementNotFound` otherwise.
+    fn generate_filtered_body(
+        &self,
+        element: &ElementAst,
+        filter: &FilterAst,
+        scope_setup: TokenStream,
+        find_all: TokenStream,
+        wrapper_code: TokenStream,
+    ) -> TokenStream {
+        let apply = format_ident!("{}", to_snake_case(filter.apply.as_deref().unwrap_or_default()));
+        let apply_args = self.generate_compose_args(&filter.args);
+        let condition = self.filter_condition(&filter.matcher).unwrap_or_else(|| quote! { false });
+
+        if !filter.find_first {
+            return quote! {
+                #scope_setup
+                let elements = #find_all.await?;
+                let mut result = Vec::new();
+                for elem in elements {
                     #wrapper_code
-                    Ok(wrapped)
+                    let value = wrapped.#apply(#apply_args).await?;
+                    if #condition {
+                        result.push(wrapped);
+                    }
+                }
+                Ok(result)
+            };
+        }
+
+        let (found, missing) = if element.nullable {
+            (quote! { Some(wrapped) }, quote! { Ok(None) })
+        } else {
+            let name = &element.name;
+            let selector = format!(
+                "{} where {} {}",
+                css_selector(element),
+                filter.apply.as_deref().unwrap_or_default(),
+                filter.matcher.matcher_type
+            );
+            let not_found = quote! {
+                Err(UtamError::ElementNotFound {
+                    name: #name.to_string(),
+                    selector: #selector.to_string(),
+                })
+            };
+            (quote! { wrapped }, not_found)
+        };
+        quote! {
+            #scope_setup
+            for elem in #find_all.await? {
+                #wrapper_code
+                let value = wrapped.#apply(#apply_args).await?;
+                if #condition {
+                    return Ok(#found);
+                }
+            }
+            #missing
+        }
+    }
+
+    /// Condition applying a filter's or assertion's matcher to `value`
+    fn filter_condition(&self, matcher: &MatcherAst) -> Option<TokenStream> {
+        let arg = self.matcher_arg(matcher)?;
+        matcher_condition(matcher, arg.as_ref())
+    }
+
+    /// A matcher's argument, or `None` if it has more than one
+    fn matcher_arg(&self, matcher: &MatcherAst) -> Option<Option<TokenStream>> {
+        match matcher.args.as_slice() {
+            [] => Some(None),
+            [arg] => Some(Some(self.generate_compose_args(std::slice::from_ref(arg)))),
+            _ => None,
+        }
+    }
+
+    /// Return `AssertionFailed` unless `value` satisfies the matcher
+    ///
+    /// The matcher must have been checked by `check_matchers`.
+    fn generate_assertion(&self, matcher: &MatcherAst) -> TokenStream {
+        let arg = self.matcher_arg(matcher).flatten();
+        let condition =
+            matcher_condition(matcher, arg.as_ref()).unwrap_or_else(|| quote! { false });
+        let expected = MatcherKind::parse(&matcher.matcher_type)
+            .map(|kind| matcher_expectation(&kind, arg.as_ref()))
+            .unwrap_or_else(|| quote! { String::new() });
+        quote! {
+            if !(#condition) {
+                return Err(UtamError::AssertionFailed {
+                    expected: #expected,
+                    actual: format!("{:?}", value),
+                });
+            }
+        }
+    }
+
+    /// Generate the statements locating an element's search context
+    ///
+    /// Returns the statements and the variable holding the context. Nested
+    /// elements are searched for by walking down from `root` through each
+    /// enclosing element, entering a shadow root wherever the next element
+    /// is declared under `shadow`. With `optional_ancestors` a missing
+    /// ancestor returns early with `None` (or an empty list).
+    fn element_scope(
+        &self,
+        scoped: &ScopedElement,
+        root: TokenStream,
+        optional_ancestors: bool,
+    ) -> (TokenStream, TokenStream) {
+        let mut setup = TokenStream::new();
+        let mut scope = root;
+        let missing = if returns_vec(scoped.element) {
+            quote! { Vec::new() }
+        } else {
+            quote! { None }
+        };
+
+        let steps = scoped
+            .ancestors
+            .iter()
+            .map(|(ancestor, in_shadow)| (Some(*ancestor), *in_shadow))
+            .chain(std::iter::once((None, scoped.in_shadow)));
+        for (ancestor, in_shadow) in steps {
+            if in_shadow {
+                setup.extend(quote! { let shadow = #scope.get_shadow_root().await?; });
+                scope = quote! { shadow };
+            }
+            if let Some(ancestor) = ancestor {
+                if optional_ancestors {
+                    let find_all = self.find_call(&scope, ancestor, true);
+                    setup.extend(quote! {
+                        let Some(parent) = #find_all.await?.into_iter().next() else {
+                            return Ok(#missing);
+                        };
+                    });
+                } else {
+                    let find = self.find_call(&scope, ancestor, false);
+                    setup.extend(quote! { let parent = #find.await?; });
                 }
+                scope = quote! { parent };
             }
         }
+
+        (setup, scope)
     }
 
-    /// Check if element is in shadow DOM
-    fn is_shadow_element(&self, element: &ElementAst) -> bool {
-        if let Some(shadow) = &self.ast.shadow {
-            shadow.elements.iter().any(|e| e.name == element.name)
-        } else {
-            false
+    /// Generate the call finding an element (or all matches) within `scope`
+    ///
+    /// CSS selectors use thirtyfour's `find`; mobile strategies go through
+    /// `Locate`. Parameterized selectors are formatted from the getter's
+    /// arguments.
+    fn find_call(&self, scope: &TokenStream, element: &ElementAst, all: bool) -> TokenStream {
+        let selector = element.selector.as_ref();
+        let by = match selector {
+            Some(selector) if selector.has_parameters() || !uses_css(Some(selector)) => {
+                generate_selector_code(selector)
+            }
+            _ => {
+                let css = css_selector(element);
+                quote! { By::Css(#css) }
+            }
+        };
+
+        match (uses_css(selector), all) {
+            (true, false) => quote! { #scope.find(#by) },
+            (true, true) => quote! { #scope.find_all(#by) },
+            (false, false) => quote! { #scope.locate(&#by) },
+            (false, true) => quote! { #scope.locate_all(&#by) },
         }
     }
 
@@ -742,23 +2340,9 @@ impl CodeGenerator {
     fn generate_element_wrapper(&self, element: &ElementAst) -> TokenStream {
         match &element.element_type {
             Some(ElementTypeAst::ActionTypes(types)) => {
-                // Determine which element wrapper to use
-                if types.iter().any(|t| t == "draggable") {
-                    quote! {
-                        let wrapped = DraggableElement::new(elem);
-                    }
-                } else if types.iter().any(|t| t == "editable") {
-                    quote! {
-                        let wrapped = EditableElement::new(elem);
-                    }
-                } else if types.iter().any(|t| t == "clickable") {
-                    quote! {
-                        let wrapped = ClickableElement::new(elem);
-                    }
-                } else {
-                    quote! {
-                        let wrapped = BaseElement::new(elem);
-                    }
+                let wrapper = action_types_constructor(types);
+                quote! {
+                    let wrapped = #wrapper::new(elem);
                 }
             }
             Some(ElementTypeAst::CustomComponent(_path)) => {
@@ -770,13 +2354,12 @@ impl CodeGenerator {
             }
             Some(ElementTypeAst::Container) => {
                 quote! {
-                    let wrapped = ContainerElement::new(elem);
+                    let wrapped = Container::new(elem);
                 }
             }
             Some(ElementTypeAst::Frame) => {
-                // Frames don't need wrapping
                 quote! {
-                    let wrapped = elem;
+                    let wrapped = FrameElement::new(elem);
                 }
             }
             None => {
@@ -789,50 +2372,78 @@ impl CodeGenerator {
 
     /// Generate compose methods
     fn generate_methods(&self, _struct_name: &proc_macro2::Ident) -> TokenStream {
-        let methods: Vec<_> = self.ast.methods.iter()
-            .map(|method| self.generate_compose_method(method))
-            .collect();
+        let methods: Vec<_> =
+            self.ast.methods.iter().map(|method| self.generate_compose_method(method)).collect();
 
         quote! { #(#methods)* }
     }
 
     /// Generate a compose method
     fn generate_compose_method(&self, method: &MethodAst) -> TokenStream {
+        let doc = self.method_doc(method);
+        let signature = self.method_signature(method);
+        let body = self.generate_compose_body(method);
+
+        quote! {
+            #doc
+            pub #signature {
+                #body
+            }
+        }
+    }
+
+    /// Signature of a compose method, without visibility
+    fn method_signature(&self, method: &MethodAst) -> TokenStream {
         let method_name = format_ident!("{}", to_snake_case(&method.name));
         let args = self.generate_method_args(method);
         let return_type = self.method_return_type(method);
-        let body = self.generate_compose_body(&method.compose);
-        
-        let doc = match &method.description {
+        let generics = self.returns_container(method).then(|| quote! { <T: RootPageObject> });
+        quote! { async fn #method_name #generics(&self, #args) -> UtamResult<#return_type> }
+    }
+
+    /// Doc comment of a compose method
+    fn method_doc(&self, method: &MethodAst) -> TokenStream {
+        match &method.description {
             Some(DescriptionAst::Simple(text)) => quote! { #[doc = #text] },
             Some(DescriptionAst::Detailed { text, .. }) => {
-                let doc_lines: Vec<_> = text.iter().map(|line| {
-                    quote! { #[doc = #line] }
-                }).collect();
+                let doc_lines: Vec<_> = text
+                    .iter()
+                    .map(|line| {
+                        quote! { #[doc = #line] }
+                    })
+                    .collect();
                 quote! { #(#doc_lines)* }
             }
             None => {
                 let doc_text = format!("{} method", method.name);
                 quote! { #[doc = #doc_text] }
             }
-        };
-
-        quote! {
-            #doc
-            pub async fn #method_name(&self, #args) -> UtamResult<#return_type> {
-                #body
-            }
         }
     }
 
     /// Generate method arguments
     fn generate_method_args(&self, method: &MethodAst) -> TokenStream {
+        let args = self.method_params(method).into_iter().map(|(arg_name, rust_type)| {
+            quote! { #arg_name: #rust_type }
+        });
+        quote! { #(#args),* }
+    }
+
+    /// Names and types of a method's arguments
+    ///
+    /// Named compose arguments that are not declared method arguments are
+    /// added after the declared ones.
+    fn method_params(&self, method: &MethodAst) -> Vec<(proc_macro2::Ident, TokenStream)> {
         // First, add explicit method args if they exist
-        let mut args: Vec<TokenStream> = method.args.iter().map(|arg| {
-            let arg_name = format_ident!("{}", to_snake_case(&arg.name));
-            let arg_type = self.rust_type_from_string(&arg.arg_type);
-            quote! { #arg_name: #arg_type }
-        }).collect();
+        let mut args: Vec<_> = method
+            .args
+            .iter()
+            .map(|arg| {
+                let arg_name = format_ident!("{}", to_snake_case(&arg.name));
+                let arg_type = self.rust_type_from_string(&arg.arg_type);
+                (arg_name, arg_type)
+            })
+            .collect();
 
         // Then collect unique args from compose statements
         let mut arg_names = std::collections::HashSet::new();
@@ -840,32 +2451,186 @@ impl CodeGenerator {
             arg_names.insert(arg.name.clone());
         }
 
-        for stmt in &method.compose {
-            for arg in &stmt.args {
+        for stmt in with_predicates(&method.compose) {
+            for arg in stmt.args.iter().chain(stmt.matcher.iter().flat_map(|m| &m.args)) {
                 if let ComposeArgAst::Named { name, arg_type } = arg {
                     if arg_names.insert(name.clone()) {
                         let arg_name = format_ident!("{}", to_snake_case(name));
                         let rust_type = self.rust_type_from_string(arg_type);
-                        args.push(quote! { #arg_name: #rust_type });
+                        args.push((arg_name, rust_type));
                     }
                 }
             }
         }
 
-        quote! { #(#args),* }
+        args
     }
 
     /// Determine method return type
+    ///
+    /// A declared `returnType` wins, then the type of the last statement's
+    /// value; `check_return_types` makes sure the two agree.
     fn method_return_type(&self, method: &MethodAst) -> TokenStream {
-        if let Some(return_type) = &method.return_type {
-            let rust_type = self.rust_type_from_string(return_type);
-            if method.return_all {
+        self.declared_return_type(method)
+            .or_else(|| self.inferred_return_type(method))
+            .unwrap_or_else(|| quote! { () })
+    }
+
+    /// Rust type of a method's explicit `returnType`, if it has one
+    fn declared_return_type(&self, method: &MethodAst) -> Option<TokenStream> {
+        let rust_type = self.return_type_from_string(method.return_type.as_ref()?);
+        Some(if method.return_all {
+            quote! { Vec<#rust_type> }
+        } else {
+            rust_type
+        })
+    }
+
+    /// Rust type of the value a method's compose statements return, or
+    /// `None` if it cannot be inferred
+    ///
+    /// With `returnElement`, that is the last statement's element;
+    /// otherwise it is the last statement's value.
+    fn inferred_return_type(&self, method: &MethodAst) -> Option<TokenStream> {
+        let last = method.compose.last()?;
+        if method.compose.iter().any(|s| s.return_element) {
+            let scoped = self.find_element(last.element.as_ref()?)?;
+            return Some(self.scoped_return_type(&scoped));
+        }
+        self.statement_type(&method.compose)
+    }
+
+    /// Whether a method returns the page object a container loads, which
+    /// makes the method generic over its type like the container's getter
+    fn returns_container(&self, method: &MethodAst) -> bool {
+        let Some(last) = method.compose.last() else { return false };
+        method.return_type.is_none()
+            && !last.chain
+            && (last.apply.is_none() || last.return_element)
+            && last
+                .element
+                .as_deref()
+                .and_then(|name| self.find_element(name))
+                .is_some_and(|scoped| is_container(scoped.element))
+    }
+
+    /// Whether a method returns the value of its last statement, rather
+    /// than `()` or the element of a `returnElement` statement
+    fn returns_value(&self, method: &MethodAst) -> bool {
+        !method.compose.iter().any(|s| s.return_element)
+            && self.method_return_type(method).to_string() != "()"
+    }
+
+    /// Rust type of the value of the last of a list of compose statements,
+    /// or `None` if it cannot be inferred
+    ///
+    /// An explicit `returnType` wins. Otherwise the value of a statement
+    /// without `apply` is its element, and with one it is what the basic
+    /// element action returns, collected into a `Vec` for a list element or
+    /// with `returnAll`. A `waitFor` returns its predicate's value, an
+    /// action chained to a basic element what that action returns, and
+    /// `document` what the document action returns.
+    fn statement_type(&self, statements: &[ComposeStatementAst]) -> Option<TokenStream> {
+        let (stmt, previous) = statements.split_last()?;
+        if let Some(return_type) = &stmt.return_type {
+            let rust_type = self.return_type_from_string(return_type);
+            return Some(if stmt.return_all {
                 quote! { Vec<#rust_type> }
             } else {
-                quote! { #rust_type }
+                rust_type
+            });
+        }
+        if let Some(predicate) = &stmt.predicate {
+            let last = predicate.last()?;
+            return if last.matcher.is_none() && self.returns_bool(last) {
+                Some(quote! { bool })
+            } else if self.is_optional_value(last) {
+                let scoped = self.find_element(last.element.as_ref()?)?;
+                Some(self.element_single_type(scoped.element))
+            } else {
+                self.statement_type(predicate)
+            };
+        }
+        if stmt.apply_external.is_some() {
+            return None;
+        }
+        if stmt.chain {
+            let Some(ChainSource::Element(_)) = self.chain_source(previous.last()?) else {
+                return None;
+            };
+            let action_type = action_return_type(stmt.apply.as_ref()?)?;
+            return Some(if stmt.return_all {
+                quote! { Vec<#action_type> }
+            } else {
+                action_type
+            });
+        }
+        match (stmt.element.as_deref(), &stmt.apply) {
+            (Some("document"), apply) => {
+                return self.document_action_type(apply.as_ref()?, &stmt.args);
             }
+            (Some("root"), Some(apply)) => return action_return_type(apply),
+            (Some("root"), None) => return Some(action_types_wrapper(&self.ast.action_types)),
+            _ => {}
+        }
+
+        let scoped = self.find_element(stmt.element.as_ref()?)?;
+        let element = scoped.element;
+        let Some(apply) = &stmt.apply else {
+            return Some(self.scoped_return_type(&scoped));
+        };
+        if !matches!(element.element_type, None | Some(ElementTypeAst::ActionTypes(_))) {
+            return None;
+        }
+        let action_type = action_return_type(apply)?;
+        if returns_vec(element) || stmt.return_all {
+            Some(quote! { Vec<#action_type> })
         } else {
-            quote! { () }
+            Some(action_type)
+        }
+    }
+
+    /// Whether a statement's value is a `bool`
+    fn returns_bool(&self, stmt: &ComposeStatementAst) -> bool {
+        match (&stmt.return_type, &stmt.apply) {
+            (Some(return_type), _) => return_type == "boolean",
+            (None, Some(apply)) if stmt.element.as_deref() == Some("document") => {
+                apply == "containsElement"
+            }
+            (None, Some(apply)) => BOOLEAN_ACTIONS.contains(&apply.as_str()),
+            (None, None) => false,
+        }
+    }
+
+    /// Whether a statement's value is the `Option` a nullable element's
+    /// getter returns
+    ///
+    /// Inside a frame, that `Option` is wrapped in `Framed`.
+    fn is_optional_value(&self, stmt: &ComposeStatementAst) -> bool {
+        let framed = |name: &str| self.find_element(name).is_some_and(|s| s.frame().is_some());
+        stmt.apply.is_none()
+            && stmt.predicate.is_none()
+            && !stmt.chain
+            && stmt
+                .element
+                .as_deref()
+                .is_some_and(|name| self.is_optional_element(name) && !framed(name))
+    }
+
+    /// Convert a UTAM return type to the Rust type of the returned value
+    ///
+    /// Unlike argument types, strings are returned owned.
+    fn return_type_from_string(&self, type_str: &str) -> TokenStream {
+        match type_str {
+            "string" => quote! { String },
+            "void" => quote! { () },
+            "actionable" => quote! { BaseElement },
+            "clickable" => quote! { ClickableElement },
+            "editable" => quote! { EditableElement },
+            "draggable" => quote! { DraggableElement },
+            "touchable" => quote! { TouchableElement },
+            path if path.contains('/') => self.custom_component_type(path),
+            _ => self.rust_type_from_string(type_str),
         }
     }
 
@@ -884,47 +2649,129 @@ impl CodeGenerator {
     }
 
     /// Generate compose method body
-    fn generate_compose_body(&self, statements: &[ComposeStatementAst]) -> TokenStream {
-        let stmts: Vec<_> = statements.iter().enumerate().map(|(i, stmt)| {
-            let is_last = i == statements.len() - 1;
-            let last_result = if is_last { Some("result") } else { None };
-            self.generate_compose_statement(stmt, last_result)
-        }).collect();
+    fn generate_compose_body(&self, method: &MethodAst) -> TokenStream {
+        let statements = &method.compose;
+        let returns_value = self.returns_value(method);
+        // The last statement's value is only bound when the body returns it
+        let returns_result = returns_value || statements.iter().any(|s| s.return_element);
+        let stmts = self.generate_compose_statements(statements, returns_value, returns_result);
+        // Methods reach the document through their root element's session
+        let document =
+            uses_document(statements).then(|| quote! { let document = Document::of(&self.root); });
 
         if statements.is_empty() {
             quote! { Ok(()) }
-        } else if statements.iter().any(|s| s.return_element) {
-            // If any statement returns an element, return it
+        } else if returns_result {
+            // Return the last statement's value, or the element it got
             quote! {
+                #document
                 #(#stmts)*
                 Ok(result)
             }
         } else {
             quote! {
+                #document
                 #(#stmts)*
                 Ok(())
             }
         }
     }
 
+    /// Generate a list of compose statements
+    ///
+    /// The last statement binds `result` if `returns_result`, to its value
+    /// if `returns_value`.
+    fn generate_compose_statements(
+        &self,
+        statements: &[ComposeStatementAst],
+        returns_value: bool,
+        returns_result: bool,
+    ) -> Vec<TokenStream> {
+        statements
+            .iter()
+            .enumerate()
+            .map(|(i, stmt)| {
+                let is_last = i == statements.len() - 1;
+                let last_result = if is_last && returns_result { Some("result") } else { None };
+                let next_chains = statements.get(i + 1).is_some_and(|next| next.chain);
+                if is_last && returns_value {
+                    self.generate_statement_value(
+                        stmt,
+                        stmt.chain && i > 0,
+                        &format_ident!("result"),
+                    )
+                } else if stmt.chain && i > 0 {
+                    self.generate_chained_statement(stmt, last_result, next_chains)
+                } else if next_chains {
+                    self.generate_statement_value(stmt, false, &format_ident!("previous"))
+                } else {
+                    self.generate_compose_statement(stmt, last_result)
+                }
+            })
+            .collect()
+    }
+
     /// Generate a single compose statement
-    fn generate_compose_statement(&self, stmt: &ComposeStatementAst, result_var: Option<&str>) -> TokenStream {
-        if let Some(element_name) = &stmt.element {
-            let getter_name = format_ident!("get_{}", to_snake_case(element_name));
-            
-            if let Some(apply) = &stmt.apply {
-                let method_name = format_ident!("{}", to_snake_case(apply));
-                let args = self.generate_compose_args(&stmt.args);
-                
-                if stmt.return_element || result_var.is_some() {
+    fn generate_compose_statement(
+        &self,
+        stmt: &ComposeStatementAst,
+        result_var: Option<&str>,
+    ) -> TokenStream {
+        if stmt.matcher.is_some() {
+            let var = format_ident!("{}", result_var.unwrap_or("value"));
+            self.generate_statement_value(stmt, false, &var)
+        } else if let Some(predicate) = &stmt.predicate {
+            let var_name = result_var.map(|var| format_ident!("{}", var));
+            self.generate_wait_for(stmt, predicate, var_name.as_ref())
+        } else if let Some(element_name) = &stmt.element {
+            let (getter_args, apply_args) = self.split_compose_args(element_name, &stmt.args);
+            let getter_args = self.generate_compose_args(getter_args);
+            let exits_frame = self.exits_frame(stmt, element_name);
+            let get_element = if exits_frame {
+                quote! { framed.value() }
+            } else {
+                self.element_expr(element_name, getter_args.clone())
+            };
+
+            let statement = if let Some(apply) = &stmt.apply {
+                let method_name = self.apply_method(apply, apply_args);
+                let args = self.generate_apply_args(element_name, apply, apply_args);
+
+                if element_name == "document" {
+                    // The document is used by later statements, so it is not moved
+                    match result_var {
+                        Some(var) => {
+                            let var_name = format_ident!("{}", var);
+                            quote! { let #var_name = document.#method_name(#args).await?; }
+                        }
+                        None => quote! { document.#method_name(#args).await?; },
+                    }
+                } else if self.is_optional_element(element_name) {
+                    // Actions on a missing nullable element are skipped
+                    if stmt.return_element || result_var.is_some() {
+                        let var_name = format_ident!("{}", result_var.unwrap_or("result"));
+                        quote! {
+                            let #var_name = #get_element;
+                            if let Some(element) = &#var_name {
+                                element.#method_name(#args).await?;
+                            }
+                        }
+                    } else {
+                        quote! {
+                            if let Some(element) = #get_element {
+                                element.#method_name(#args).await?;
+                            }
+                        }
+                    }
+                } else if stmt.return_element || result_var.is_some() {
                     let var_name = format_ident!("{}", result_var.unwrap_or("result"));
                     quote! {
-                        let #var_name = self.#getter_name().await?;
+                        let #var_name = #get_element;
                         #var_name.#method_name(#args).await?;
                     }
                 } else {
                     quote! {
-                        let element = self.#getter_name().await?;
+                        let element = #get_element;
                         element.#method_name(#args).await?;
                     }
                 }
@@ -933,65 +2780,449 @@ impl CodeGenerator {
                 if stmt.return_element || result_var.is_some() {
                     let var_name = format_ident!("{}", result_var.unwrap_or("result"));
                     quote! {
-                        let #var_name = self.#getter_name().await?;
+                        let #var_name = #get_element;
                     }
                 } else {
                     quote! {
-                        let _element = self.#getter_name().await?;
+                        let _element = #get_element;
                     }
                 }
+            };
+            if exits_frame {
+                self.in_frame(element_name, &getter_args, statement, None)
+            } else {
+                statement
             }
         } else if let Some(apply_external) = &stmt.apply_external {
             // External method call
             let method_name = format_ident!("{}", to_snake_case(&apply_external.method));
             let args = self.generate_compose_args(&apply_external.args);
-            
+
             quote! {
                 #method_name(#args).await?;
             }
         } else if let Some(apply) = &stmt.apply {
-            // Direct apply without element (like waitFor on root)
+            // Apply without element calls another method of this page object
             let method_name = format_ident!("{}", to_snake_case(apply));
             let args = self.generate_compose_args(&stmt.args);
-            
+
             quote! {
-                self.root.#method_name(#args).await?;
+                self.#method_name(#args).await?;
             }
         } else {
             quote! {}
         }
     }
 
+    /// Whether a statement applies an action to an element declared inside
+    /// a frame, and so switches back out of the frame once it is done
+    ///
+    /// A statement returning the element leaves that to the caller.
+    fn exits_frame(&self, stmt: &ComposeStatementAst, element_name: &str) -> bool {
+        stmt.apply.is_some()
+            && !stmt.return_element
+            && self.find_element(element_name).is_some_and(|scoped| scoped.frame().is_some())
+    }
+
+    /// Run `statement` on an element declared inside a frame, bound to
+    /// `framed`, then switch back to the parent frame
+    ///
+    /// The frame is exited even if the statement fails, and the statement's
+    /// error wins over one from exiting. `var`, if the statement binds one,
+    /// is bound again after the frame is exited.
+    fn in_frame(
+        &self,
+        element_name: &str,
+        getter_args: &TokenStream,
+        statement: TokenStream,
+        var: Option<&proc_macro2::Ident>,
+    ) -> TokenStream {
+        let getter_name = format_ident!("get_{}", to_snake_case(element_name));
+        let (returned, rebind) = match var {
+            Some(var) => (quote! { #var }, quote! { let #var = value?; }),
+            None => (quote! { () }, quote! { value?; }),
+        };
+        quote! {
+            let framed = self.#getter_name(#getter_args).await?;
+            let value: UtamResult<_> = async {
+                #statement
+                Ok(#returned)
+            }
+            .await;
+            let exited = framed.exit().await;
+            #rebind
+            exited?;
+        }
+    }
+
+    /// Expression getting a compose statement's element from this page object
+    ///
+    /// `root` is the page object's own root element, wrapped for its
+    /// declared action types, and `document` is the current page. In
+    /// `beforeLoad` there is no page object yet, so `root` is looked up.
+    fn element_expr(&self, element_name: &str, getter_args: TokenStream) -> TokenStream {
+        match element_name {
+            "root" if self.before_load => {
+                let root_type = action_types_wrapper(&self.ast.action_types);
+                quote! { #root_type::new(root().await?) }
+            }
+            "root" => {
+                let root_type = action_types_constructor(&self.ast.action_types);
+                quote! { #root_type::new(self.root.clone()) }
+            }
+            "document" => quote! { document },
+            _ => {
+                let getter_name = format_ident!("get_{}", to_snake_case(element_name));
+                quote! { self.#getter_name(#getter_args).await? }
+            }
+        }
+    }
+
+    /// Generate a statement binding its value to `var`
+    ///
+    /// With an `apply`, the value is what the method returns rather than
+    /// the element it is applied to. A `chained` statement looks its element
+    /// or method up on `previous` instead of `self`, and passes its args to
+    /// the applied method if there is one, otherwise to the element getter.
+    /// A statement with a `matcher` asserts the value matches first.
+    fn generate_statement_value(
+        &self,
+        stmt: &ComposeStatementAst,
+        chained: bool,
+        var: &proc_macro2::Ident,
+    ) -> TokenStream {
+        let Some(matcher) = &stmt.matcher else {
+            return self.generate_unmatched_value(stmt, chained, var);
+        };
+        let value = format_ident!("value");
+        let get_value = self.generate_unmatched_value(stmt, chained, &value);
+        let assertion = self.generate_assertion(matcher);
+        let rebind = (*var != value).then(|| quote! { let #var = value; });
+        quote! {
+            #get_value
+            #assertion
+            #rebind
+        }
+    }
+
+    /// Generate a statement binding its value to `var`, ignoring its matcher
+    fn generate_unmatched_value(
+        &self,
+        stmt: &ComposeStatementAst,
+        chained: bool,
+        var: &proc_macro2::Ident,
+    ) -> TokenStream {
+        if let Some(predicate) = &stmt.predicate {
+            return self.generate_wait_for(stmt, predicate, Some(var));
+        }
+        match (&stmt.element, &stmt.apply) {
+            (Some(element_name), apply) => {
+                let no_args: &[ComposeArgAst] = &[];
+                let (getter_args, apply_args) = match (chained, apply) {
+                    (false, _) => self.split_compose_args(element_name, &stmt.args),
+                    (true, Some(_)) => (no_args, stmt.args.as_slice()),
+                    (true, None) => (stmt.args.as_slice(), no_args),
+                };
+                let getter_args = self.generate_compose_args(getter_args);
+                let exits_frame = !chained && self.exits_frame(stmt, element_name);
+                let element = if chained {
+                    let getter_name = format_ident!("get_{}", to_snake_case(element_name));
+                    quote! { previous.#getter_name(#getter_args).await? }
+                } else if exits_frame {
+                    quote! { framed.value() }
+                } else {
+                    self.element_expr(element_name, getter_args.clone())
+                };
+                let is_list = !chained
+                    && self.find_element(element_name).is_some_and(|s| returns_vec(s.element));
+                let statement = match apply {
+                    // The action is applied to every element of a list
+                    Some(apply) if is_list => {
+                        let method_name = format_ident!("{}", to_snake_case(apply));
+                        let args = self.generate_compose_args(apply_args);
+                        quote! {
+                            let mut #var = Vec::new();
+                            for element in #element {
+                                #var.push(element.#method_name(#args).await?);
+                            }
+                        }
+                    }
+                    Some(apply) => {
+                        let method_name = self.apply_method(apply, apply_args);
+                        let args = self.generate_apply_args(element_name, apply, apply_args);
+                        quote! {
+                            let #var = #element.#method_name(#args).await?;
+                        }
+                    }
+                    None => quote! {
+                        let #var = #element;
+                    },
+                };
+                if exits_frame {
+                    self.in_frame(element_name, &getter_args, statement, Some(var))
+                } else {
+                    statement
+                }
+            }
+            (None, Some(apply)) => {
+                let method_name = format_ident!("{}", to_snake_case(apply));
+                let args = self.generate_compose_args(&stmt.args);
+                let target = if chained {
+                    quote! { previous }
+                } else {
+                    quote! { self }
+                };
+                quote! {
+                    let #var = #target.#method_name(#args).await?;
+                }
+            }
+            (None, None) => match &stmt.apply_external {
+                Some(apply_external) => {
+                    let method_name = format_ident!("{}", to_snake_case(&apply_external.method));
+                    let args = self.generate_compose_args(&apply_external.args);
+                    quote! {
+                        let #var = #method_name(#args).await?;
+                    }
+                }
+                None => quote! {},
+            },
+        }
+    }
+
+    /// Generate a statement chained to the result of the previous one
+    ///
+    /// When the next statement chains too, this statement's value is bound
+    /// to `previous` in turn.
+    fn generate_chained_statement(
+        &self,
+        stmt: &ComposeStatementAst,
+        result_var: Option<&str>,
+        next_chains: bool,
+    ) -> TokenStream {
+        if next_chains {
+            return self.generate_statement_value(stmt, true, &format_ident!("previous"));
+        }
+        if stmt.matcher.is_some() {
+            let var = format_ident!("{}", result_var.unwrap_or("value"));
+            return self.generate_statement_value(stmt, true, &var);
+        }
+        let binding = (stmt.return_element || result_var.is_some())
+            .then(|| format_ident!("{}", result_var.unwrap_or("result")));
+        let args = self.generate_compose_args(&stmt.args);
+
+        match (&stmt.element, &stmt.apply, binding) {
+            (Some(element_name), Some(apply), Some(var_name)) => {
+                let getter_name = format_ident!("get_{}", to_snake_case(element_name));
+                let method_name = format_ident!("{}", to_snake_case(apply));
+                quote! {
+                    let #var_name = previous.#getter_name().await?;
+                    #var_name.#method_name(#args).await?;
+                }
+            }
+            (_, _, Some(var_name)) => self.generate_statement_value(stmt, true, &var_name),
+            (Some(_), None, None) => {
+                self.generate_statement_value(stmt, true, &format_ident!("_element"))
+            }
+            (Some(element_name), Some(apply), None) => {
+                let getter_name = format_ident!("get_{}", to_snake_case(element_name));
+                let method_name = format_ident!("{}", to_snake_case(apply));
+                quote! {
+                    previous.#getter_name().await?.#method_name(#args).await?;
+                }
+            }
+            (None, Some(apply), None) => {
+                let method_name = format_ident!("{}", to_snake_case(apply));
+                quote! {
+                    previous.#method_name(#args).await?;
+                }
+            }
+            (None, None, None) => quote! {},
+        }
+    }
+
+    /// Generate a `waitFor` statement that polls its predicate until it holds
+    ///
+    /// The predicate's statements run on every poll. It holds once the last
+    /// one returns `true` or a non-null value, which becomes the statement's
+    /// value. The timeout is the statement's argument in milliseconds if it
+    /// has one, then the configured wait timeout, then `WaitConfig`'s default.
+    fn generate_wait_for(
+        &self,
+        stmt: &ComposeStatementAst,
+        predicate: &[ComposeStatementAst],
+        var: Option<&proc_macro2::Ident>,
+    ) -> TokenStream {
+        let timeout = match stmt.args.first() {
+            Some(arg) => {
+                let millis = self.generate_compose_args(std::slice::from_ref(arg));
+                quote! { std::time::Duration::from_millis(#millis as u64) }
+            }
+            None => self.default_timeout(),
+        };
+        let wait_config = self.wait_config();
+
+        let value = format_ident!("value");
+        let previous = format_ident!("previous");
+        let stmts: Vec<_> = predicate
+            .iter()
+            .enumerate()
+            .map(|(i, stmt)| {
+                let chained = stmt.chain && i > 0;
+                if i == predicate.len() - 1 {
+                    self.generate_statement_value(stmt, chained, &value)
+                } else if predicate.get(i + 1).is_some_and(|next| next.chain) {
+                    self.generate_statement_value(stmt, chained, &previous)
+                } else if chained {
+                    self.generate_chained_statement(stmt, None, false)
+                } else {
+                    self.generate_compose_statement(stmt, None)
+                }
+            })
+            .collect();
+        let holds = match predicate.last() {
+            Some(last) => self.predicate_holds(last),
+            None => quote! { Some(()) },
+        };
+
+        let wait = quote! {
+            {
+                let timeout = #timeout;
+                let config = #wait_config;
+                wait_for(
+                    || async {
+                        #(#stmts)*
+                        Ok(#holds)
+                    },
+                    &config,
+                    "waitFor predicate to hold",
+                )
+                .await?
+            }
+        };
+        match var {
+            Some(var) => quote! { let #var = #wait; },
+            None => quote! { #wait; },
+        }
+    }
+
+    /// `Option` of the predicate's result `value`: `Some` once it is `true`
+    /// or non-null
+    ///
+    /// Only the getter of a nullable element on this page object returns an
+    /// `Option` already, so only then is null distinguishable.
+    fn predicate_holds(&self, last: &ComposeStatementAst) -> TokenStream {
+        if last.matcher.is_some() {
+            // The matcher's assertion fails the poll until it holds
+            return quote! { Some(value) };
+        }
+
+        if self.returns_bool(last) {
+            quote! { value.then_some(true) }
+        } else if self.is_optional_value(last) {
+            quote! { value }
+        } else {
+            quote! { Some(value) }
+        }
+    }
+
+    /// Split a compose statement's args into the element getter's selector
+    /// and filter arguments and the arguments of the applied action
+    ///
+    /// Getter arguments come first, as in the UTAM grammar.
+    fn split_compose_args<'s>(
+        &self,
+        element_name: &str,
+        args: &'s [ComposeArgAst],
+    ) -> (&'s [ComposeArgAst], &'s [ComposeArgAst]) {
+        let getter_args =
+            self.find_element(element_name).map(|scoped| scoped.params().len()).unwrap_or(0);
+        args.split_at(getter_args.min(args.len()))
+    }
+
     /// Generate arguments for compose statement
+    ///
+    /// Page object type arguments are generic parameters of the applied
+    /// method rather than values, so they are left out.
     fn generate_compose_args(&self, args: &[ComposeArgAst]) -> TokenStream {
-        let arg_tokens: Vec<_> = args.iter().map(|arg| {
-            match arg {
+        let arg_tokens: Vec<_> = args
+            .iter()
+            .filter_map(|arg| match arg {
                 ComposeArgAst::Named { name, .. } => {
                     let ident = format_ident!("{}", to_snake_case(name));
-                    quote! { #ident }
+                    Some(quote! { #ident })
                 }
-                ComposeArgAst::Value(value) => {
-                    // Convert JSON value to Rust literal
-                    match value {
-                        serde_json::Value::String(s) => quote! { #s },
-                        serde_json::Value::Number(n) => {
-                            if let Some(i) = n.as_i64() {
-                                quote! { #i }
-                            } else if let Some(f) = n.as_f64() {
-                                quote! { #f }
-                            } else {
-                                quote! { 0 }
-                            }
-                        }
-                        serde_json::Value::Bool(b) => quote! { #b },
-                        _ => quote! { () },
-                    }
+                ComposeArgAst::Literal { arg_type, .. }
+                    if is_page_object_type(arg_type.as_deref()) =>
+                {
+                    None
                 }
-            }
-        }).collect();
+                ComposeArgAst::Literal { value, arg_type } => {
+                    Some(self.generate_literal_arg(value, arg_type.as_deref()))
+                }
+                ComposeArgAst::Value(value) => Some(json_literal(value)),
+            })
+            .collect();
 
         quote! { #(#arg_tokens),* }
     }
+
+    /// Generate a literal argument
+    ///
+    /// A `locator` becomes a `Selector`, and a `frameElement` or
+    /// `elementReference` names one of this page object's elements.
+    /// Untyped literals are plain Rust literals.
+    fn generate_literal_arg(
+        &self,
+        value: &serde_json::Value,
+        arg_type: Option<&str>,
+    ) -> TokenStream {
+        match arg_type {
+            Some("locator") => match serde_json::from_value::<SelectorAst>(value.clone()) {
+                Ok(selector) => {
+                    let selector = generate_selector_value(&selector);
+                    quote! { &#selector }
+                }
+                Err(_) => quote! { compile_error!("locator argument must be a selector") },
+            },
+            Some("frameElement" | "elementReference") => {
+                let name = value.as_str().unwrap_or_default();
+                let getter_name = format_ident!("get_{}", to_snake_case(name));
+                quote! { &self.#getter_name().await? }
+            }
+            _ => json_literal(value),
+        }
+    }
+
+    /// Method a compose statement applies, with the page object types among
+    /// its arguments as generic parameters
+    fn apply_method(&self, apply: &str, args: &[ComposeArgAst]) -> TokenStream {
+        let method_name = format_ident!("{}", to_snake_case(apply));
+        match page_object_arg(args) {
+            Some(path) => {
+                let page_object = self.custom_component_type(path);
+                quote! { #method_name::<#page_object> }
+            }
+            None => quote! { #method_name },
+        }
+    }
+
+    /// Generate the arguments of an action applied to an element
+    ///
+    /// `waitForDocumentReady` takes no arguments in UTAM, but waits with
+    /// the configured wait.
+    fn generate_apply_args(
+        &self,
+        element_name: &str,
+        apply: &str,
+        args: &[ComposeArgAst],
+    ) -> TokenStream {
+        if element_name == "document" && apply == "waitForDocumentReady" {
+            let wait_config = self.configured_wait_config();
+            quote! { &#wait_config }
+        } else {
+            self.generate_compose_args(args)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1025,14 +3256,8 @@ mod tests {
 
     #[test]
     fn test_utam_type_to_rust_custom() {
-        assert_eq!(
-            utam_type_to_rust("utam-applications/pageObjects/component"),
-            "Component"
-        );
-        assert_eq!(
-            utam_type_to_rust("package/pageObjects/my-button"),
-            "MyButton"
-        );
+        assert_eq!(utam_type_to_rust("utam-applications/pageObjects/component"), "Component");
+        assert_eq!(utam_type_to_rust("package/pageObjects/my-button"), "MyButton");
     }
 
     #[test]
@@ -1041,14 +3266,8 @@ mod tests {
             name: "loginUser".to_string(),
             description: None,
             args: vec![
-                MethodArgAst {
-                    name: "username".to_string(),
-                    arg_type: "string".to_string(),
-                },
-                MethodArgAst {
-                    name: "password".to_string(),
-                    arg_type: "string".to_string(),
-                },
+                MethodArgAst { name: "username".to_string(), arg_type: "string".to_string() },
+                MethodArgAst { name: "password".to_string(), arg_type: "string".to_string() },
             ],
             compose: vec![],
             return_type: None,
@@ -1072,16 +3291,21 @@ mod tests {
         assert_eq!(compiled, CompiledArg::Literal("\"test\"".to_string()));
     }
 
+    #[test]
+    fn test_compile_single_arg_untyped_value() {
+        let arg: ComposeArgAst = serde_json::from_str(r#"{ "value": 400 }"#).unwrap();
+        let compiled = compile_single_arg(&arg, &[]).unwrap();
+        assert_eq!(compiled, CompiledArg::Literal("400".to_string()));
+    }
+
     #[test]
     fn test_compile_single_arg_reference() {
         let arg = ComposeArgAst::Named {
             name: "username".to_string(),
             arg_type: "argumentReference".to_string(),
         };
-        let method_args = vec![MethodArgAst {
-            name: "username".to_string(),
-            arg_type: "string".to_string(),
-        }];
+        let method_args =
+            vec![MethodArgAst { name: "username".to_string(), arg_type: "string".to_string() }];
         let compiled = compile_single_arg(&arg, &method_args).unwrap();
         assert_eq!(compiled, CompiledArg::ArgumentReference("username".to_string()));
     }
@@ -1131,10 +3355,8 @@ mod tests {
             predicate: None,
         }];
 
-        let method_args = vec![MethodArgAst {
-            name: "username".to_string(),
-            arg_type: "string".to_string(),
-        }];
+        let method_args =
+            vec![MethodArgAst { name: "username".to_string(), arg_type: "string".to_string() }];
 
         let compiled = compile_compose_statements(&statements, &method_args, &[]).unwrap();
         assert_eq!(compiled.len(), 1);
@@ -1145,6 +3367,8 @@ mod tests {
             }
             _ => panic!("Expected ApplyAction"),
         }
+    }
+
     use crate::ast::SelectorArgAst;
 
     #[test]
@@ -1220,8 +3444,11 @@ mod tests {
 
         let code = generate_selector_code(&selector);
         let code_str = code.to_string();
-        assert!(code_str.contains("thirtyfour :: By :: Id"));
+        assert!(code_str.contains("Selector :: AccessibilityId"));
         assert!(code_str.contains("submit-button"));
+    }
+
+    #[test]
     fn test_generate_simple_page_object() {
         let ast = PageObjectAst {
             description: Some(DescriptionAst::Simple("Test page".to_string())),
@@ -1246,9 +3473,8 @@ mod tests {
             metadata: None,
         };
 
-        let config = CodeGenConfig {
-            module_name: Some("TestPage".to_string()),
-        };
+        let config =
+            CodeGenConfig { module_name: Some("TestPage".to_string()), ..Default::default() };
 
         let generator = CodeGenerator::new(ast, config);
         let code = generator.generate().unwrap();
@@ -1304,9 +3530,8 @@ mod tests {
             metadata: None,
         };
 
-        let config = CodeGenConfig {
-            module_name: Some("TestForm".to_string()),
-        };
+        let config =
+            CodeGenConfig { module_name: Some("TestForm".to_string()), ..Default::default() };
 
         let generator = CodeGenerator::new(ast, config);
         let code = generator.generate().unwrap();
@@ -1334,29 +3559,27 @@ mod tests {
             implements: None,
             is_interface: false,
             shadow: None,
-            elements: vec![
-                ElementAst {
-                    name: "usernameInput".to_string(),
-                    element_type: Some(ElementTypeAst::ActionTypes(vec!["editable".to_string()])),
-                    selector: Some(SelectorAst {
-                        css: Some("input[name='username']".to_string()),
-                        accessid: None,
-                        classchain: None,
-                        uiautomator: None,
-                        args: vec![],
-                        return_all: false,
-                    }),
-                    public: false,
-                    nullable: false,
-                    generate_wait: false,
-                    load: false,
-                    shadow: None,
-                    elements: vec![],
-                    filter: None,
-                    description: None,
-                    list: false,
-                },
-            ],
+            elements: vec![ElementAst {
+                name: "usernameInput".to_string(),
+                element_type: Some(ElementTypeAst::ActionTypes(vec!["editable".to_string()])),
+                selector: Some(SelectorAst {
+                    css: Some("input[name='username']".to_string()),
+                    accessid: None,
+                    classchain: None,
+                    uiautomator: None,
+                    args: vec![],
+                    return_all: false,
+                }),
+                public: false,
+                nullable: false,
+                generate_wait: false,
+                load: false,
+                shadow: None,
+                elements: vec![],
+                filter: None,
+                description: None,
+                list: false,
+            }],
             methods: vec![MethodAst {
                 name: "setUsername".to_string(),
                 description: None,
@@ -1384,9 +3607,8 @@ mod tests {
             metadata: None,
         };
 
-        let config = CodeGenConfig {
-            module_name: Some("LoginForm".to_string()),
-        };
+        let config =
+            CodeGenConfig { module_name: Some("LoginForm".to_string()), ..Default::default() };
 
         let generator = CodeGenerator::new(ast, config);
         let code = generator.generate().unwrap();
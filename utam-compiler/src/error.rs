@@ -33,6 +33,103 @@ pub enum CompilerError {
     /// Invalid statement in compose method
     #[error("Invalid statement: {0}")]
     InvalidStatement(String),
+
+    /// Two elements whose getters would be generated with the same name
+    #[error("Elements '{first}' and '{second}' are both generated as '{name}'")]
+    #[diagnostic(
+        code(utam::duplicate_element),
+        help("Every element, nested and shadow ones included, gets its getter on the page object, so element names must be unique across all scopes")
+    )]
+    DuplicateElement { first: String, second: String, name: String },
+
+    /// Element getter that would take two parameters with the same name
+    #[error("Getter of '{element}' would take two parameters named '{name}'")]
+    #[diagnostic(
+        code(utam::duplicate_parameter),
+        help("Rename one of the selector args; a nested element's getter takes the args of every enclosing selector before its own, and wait_for_<element> also takes 'timeout'")
+    )]
+    DuplicateParameter { element: String, name: String },
+
+    /// Selector strategy not available on the page object's platform
+    #[error("Selector '{strategy}' of '{element}' is not supported on platform '{platform}'")]
+    #[diagnostic(
+        code(utam::unsupported_selector),
+        help("Use css for web page objects, or accessid, classchain or uiautomator with \"platform\": \"native\"")
+    )]
+    UnsupportedSelector { element: String, strategy: String, platform: String },
+
+    /// Element filter that cannot be generated
+    #[error("Invalid filter on '{element}': {message}")]
+    #[diagnostic(
+        code(utam::invalid_filter),
+        help("Filters need an apply method and one of the matchers isTrue, isFalse, notNull, stringContains or stringEquals")
+    )]
+    InvalidFilter { element: String, message: String },
+
+    /// Compose method generated with the same name as an element getter
+    #[error(
+        "Method '{method}' and the getter of element '{element}' are both generated as '{name}'"
+    )]
+    #[diagnostic(
+        code(utam::name_collision),
+        help("Rename the method; element getters are named get_<element>")
+    )]
+    NameCollision { method: String, element: String, name: String },
+
+    /// Element declared inside a frame that its getter cannot enter
+    #[error("Invalid frame for '{element}': {message}")]
+    #[diagnostic(
+        code(utam::invalid_frame),
+        help("Declare elements inside a single frame that is neither a list nor nullable")
+    )]
+    InvalidFrame { element: String, message: String },
+
+    /// Container element whose getter cannot load a page object
+    #[error("Invalid container '{element}': {message}")]
+    #[diagnostic(
+        code(utam::invalid_container),
+        help("Declare containers as single elements outside of frames")
+    )]
+    InvalidContainer { element: String, message: String },
+
+    /// Element marked `load` that cannot be checked when its page object loads
+    #[error("Invalid load for '{element}': {message}")]
+    #[diagnostic(
+        code(utam::invalid_load),
+        help("Load only elements of root page objects without selector or filter arguments, outside of frames and containers")
+    )]
+    InvalidLoad { element: String, message: String },
+
+    /// Custom component type that matches no page object in the project
+    #[error("Unresolved custom component '{reference}'")]
+    #[diagnostic(
+        code(utam::unresolved_component),
+        help("Compile the page object defining this type with the project, or map its package to a Rust module")
+    )]
+    UnresolvedComponent {
+        reference: String,
+        #[source_code]
+        src: NamedSource<String>,
+        #[label("no page object has this type")]
+        span: SourceSpan,
+    },
+
+    /// Declared method return type that differs from the inferred one
+    #[error("Method '{method}' declares return type {declared}, but its last statement returns {inferred}")]
+    #[diagnostic(
+        code(utam::return_type_mismatch),
+        help("Remove the method's returnType to use the inferred type, or declare the type its last statement returns")
+    )]
+    ReturnTypeMismatch { method: String, declared: String, inferred: String },
+
+    /// Method ending in a chained statement whose value type is unknown
+    #[error("Method '{method}' ends in a chained statement whose return type cannot be inferred")]
+    #[diagnostic(
+        code(utam::unknown_return_type),
+        help("Declare the method's returnType, or \"void\" if it returns nothing")
+    )]
+    UnknownReturnType { method: String },
+
     /// Invalid element type error
     #[error("Invalid element type")]
     #[diagnostic(
@@ -73,6 +170,8 @@ pub enum CompilerError {
         src: NamedSource<String>,
         #[label("selector with {expected} placeholder(s)")]
         span: SourceSpan,
+    },
+
     /// Selector validation error
     #[error("Selector validation error: {0}")]
     Selector(#[from] SelectorError),
@@ -82,12 +181,35 @@ pub enum CompilerError {
 #[derive(Error, Debug, Diagnostic)]
 pub enum SelectorError {
     /// Parameter count mismatch between placeholders and args
-    #[error("Parameter count mismatch: expected {expected} placeholders but got {actual} arguments")]
+    #[error(
+        "Parameter count mismatch: expected {expected} placeholders but got {actual} arguments"
+    )]
     #[diagnostic(help("Ensure the number of %s and %d placeholders matches the number of args"))]
-    ParameterMismatch {
-        expected: usize,
-        actual: usize,
-    },
+    ParameterMismatch { expected: usize, actual: usize },
+}
+
+/// Check of `PageObjectAst::validate` that found a problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AstCheck {
+    /// An element fails `ElementAst::validate`
+    Element,
+    /// Two elements share a name
+    DuplicateName,
+    /// A selector's placeholders do not match its arguments
+    SelectorParameters,
+    /// A selector strategy is not available on the page object's platform
+    PlatformSelector,
+}
+
+/// Problem found by `PageObjectAst::validate`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AstValidationError {
+    /// Check that found the problem
+    pub check: AstCheck,
+    /// JSON pointer to the value the problem is about
+    pub path: String,
+    /// Human-readable error message
+    pub message: String,
 }
 
 /// Detailed validation error with path and message
@@ -138,6 +260,11 @@ impl ErrorReporter {
         Self { source, file_path }
     }
 
+    /// Get the source code being reported on
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
     /// Report an error to stderr with colorized output
     ///
     /// Uses miette's fancy formatting for terminal output with colors,
@@ -151,8 +278,7 @@ impl ErrorReporter {
 
         // Create a graphical report handler with fancy theme
         let mut output = String::new();
-        let handler =
-            GraphicalReportHandler::new_themed(GraphicalTheme::unicode()).with_width(80);
+        let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode()).with_width(80);
 
         // Format the error using miette's fancy formatting
         if let Err(e) = handler.render_report(&mut output, error) {
@@ -187,7 +313,6 @@ impl ErrorReporter {
             })
             .collect();
 
-        serde_json::to_string_pretty(&error_objects)
-            .unwrap_or_else(|_| "[]".to_string())
+        serde_json::to_string_pretty(&error_objects).unwrap_or_else(|_| "[]".to_string())
     }
 }
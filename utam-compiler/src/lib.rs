@@ -15,22 +15,37 @@
 pub mod ast;
 pub mod codegen;
 pub mod error;
+pub mod location;
 mod parser;
+pub mod project;
 pub mod utils;
 pub mod validator;
 
 pub use codegen::{CodeGenConfig, CodeGenerator};
-pub use error::{CompilerError, CompilerResult, ErrorReporter, SelectError, ValidationError};
+pub use error::{
+    AstCheck, AstValidationError, CompilerError, CompilerResult, ErrorReporter, SelectorError,
+    ValidationError,
+};
+pub use project::SymbolTable;
 pub use validator::SchemaValidator;
 
 // Re-export AST types for convenience
 pub use ast::*;
 
 /// Compile UTAM JSON to Rust source code
+///
+/// When `config.symbols` is set, every custom component type must resolve
+/// through it or through `config.package_modules`.
 pub fn compile(json: &str, config: CodeGenConfig) -> CompilerResult<String> {
     // Parse JSON to AST
     let ast: PageObjectAst = serde_json::from_str(json)?;
-    
+
+    // Check custom component references against the project
+    if let Some(symbols) = &config.symbols {
+        let name = config.module_name.as_deref().unwrap_or("page object");
+        symbols.check_references(&ast, &config.package_modules, name, json)?;
+    }
+
     // Generate code
     let generator = CodeGenerator::new(ast, config);
     generator.generate()
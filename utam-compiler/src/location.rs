@@ -0,0 +1,240 @@
+//! Source positions for JSON pointers
+//!
+//! Schema and AST validation report problems as JSON pointers such as
+//! `/elements/0/selector`. Diagnostics need line and column numbers, so
+//! this module scans the original source text to find where the value a
+//! pointer refers to starts.
+
+/// Byte offset of the value `pointer` refers to in `source`
+///
+/// Returns `None` if the pointer does not resolve or the source is not
+/// well-formed JSON along the way.
+pub fn pointer_offset(source: &str, pointer: &str) -> Option<usize> {
+    let segments: Vec<String> = pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect();
+    let mut scanner = Scanner { bytes: source.as_bytes(), source, pos: 0 };
+    scanner.find(&segments)
+}
+
+/// Byte offset and length of the value `pointer` refers to in `source`
+///
+/// Returns `None` under the same conditions as [`pointer_offset`].
+pub fn pointer_span(source: &str, pointer: &str) -> Option<(usize, usize)> {
+    let start = pointer_offset(source, pointer)?;
+    let mut scanner = Scanner { bytes: source.as_bytes(), source, pos: start };
+    scanner.skip_value()?;
+    Some((start, scanner.pos - start))
+}
+
+/// 1-based line and column of a byte offset, counting columns in characters
+pub fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// 1-based line and column of the value `pointer` refers to
+///
+/// Falls back to the start of the file when the pointer does not resolve.
+pub fn pointer_line_col(source: &str, pointer: &str) -> (usize, usize) {
+    line_col(source, pointer_offset(source, pointer).unwrap_or(0))
+}
+
+/// Minimal JSON scanner that walks to a value without building a tree
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    source: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    /// Walk down `segments` from the value at the current position
+    fn find(&mut self, segments: &[String]) -> Option<usize> {
+        self.skip_whitespace();
+        let Some((segment, rest)) = segments.split_first() else {
+            return Some(self.pos);
+        };
+
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                loop {
+                    self.skip_whitespace();
+                    if self.peek()? == b'}' {
+                        return None;
+                    }
+                    let key = self.string()?;
+                    self.skip_whitespace();
+                    self.expect(b':')?;
+                    if key == *segment {
+                        return self.find(rest);
+                    }
+                    self.skip_value()?;
+                    self.next_item()?;
+                }
+            }
+            b'[' => {
+                let index: usize = segment.parse().ok()?;
+                self.pos += 1;
+                for _ in 0..index {
+                    self.skip_whitespace();
+                    if self.peek()? == b']' {
+                        return None;
+                    }
+                    self.skip_value()?;
+                    self.next_item()?;
+                }
+                self.skip_whitespace();
+                if self.peek()? == b']' {
+                    return None;
+                }
+                self.find(rest)
+            }
+            _ => None,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.peek()? != byte {
+            return None;
+        }
+        self.pos += 1;
+        Some(())
+    }
+
+    /// Consume the separator after an object member or array element
+    ///
+    /// Fails at the end of the container, which callers treat as "not found".
+    fn next_item(&mut self) -> Option<()> {
+        self.skip_whitespace();
+        self.expect(b',')
+    }
+
+    /// Consume a string literal and return its decoded value
+    fn string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.skip_string()?;
+        serde_json::from_str(&self.source[start..self.pos]).ok()
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        self.expect(b'"')?;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_value(&mut self) -> Option<()> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'"' => self.skip_string(),
+            b'{' | b'[' => {
+                let mut depth = 0usize;
+                loop {
+                    match self.peek()? {
+                        b'"' => {
+                            self.skip_string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Some(());
+                            }
+                        }
+                        _ => {}
+                    }
+                    self.pos += 1;
+                }
+            }
+            _ => {
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+                Some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"{
+  "root": true,
+  "selector": { "css": ".app" },
+  "elements": [
+    { "name": "first", "selector": { "css": "a[title=\"}\"]" } },
+    {
+      "name": "second",
+      "selector": { "css": "b" }
+    }
+  ]
+}"#;
+
+    #[test]
+    fn test_root_pointer() {
+        assert_eq!(pointer_line_col(SOURCE, ""), (1, 1));
+    }
+
+    #[test]
+    fn test_object_member() {
+        assert_eq!(pointer_line_col(SOURCE, "/selector"), (3, 15));
+        assert_eq!(pointer_line_col(SOURCE, "/selector/css"), (3, 24));
+    }
+
+    #[test]
+    fn test_array_element_after_tricky_string() {
+        assert_eq!(pointer_line_col(SOURCE, "/elements/1"), (6, 5));
+        assert_eq!(pointer_line_col(SOURCE, "/elements/1/name"), (7, 15));
+    }
+
+    #[test]
+    fn test_value_span() {
+        let (start, len) = pointer_span(SOURCE, "/elements/0/selector/css").unwrap();
+        assert_eq!(&SOURCE[start..start + len], r#""a[title=\"}\"]""#);
+
+        let (start, len) = pointer_span(SOURCE, "/elements/1/selector").unwrap();
+        assert_eq!(&SOURCE[start..start + len], r#"{ "css": "b" }"#);
+    }
+
+    #[test]
+    fn test_unresolved_pointer() {
+        assert_eq!(pointer_offset(SOURCE, "/elements/5"), None);
+        assert_eq!(pointer_offset(SOURCE, "/missing"), None);
+        assert_eq!(pointer_line_col(SOURCE, "/missing"), (1, 1));
+    }
+
+    #[test]
+    fn test_escaped_pointer_segment() {
+        let source = r#"{ "a/b": 1 }"#;
+        assert_eq!(pointer_offset(source, "/a~1b"), Some(9));
+    }
+}
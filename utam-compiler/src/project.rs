@@ -0,0 +1,323 @@
+//! Project-level resolution of custom component types
+//!
+//! A page object compiled on its own can only name a custom component by
+//! the last segment of its type path, and cannot tell whether that
+//! component exists. When a whole project is compiled, a [`SymbolTable`]
+//! built from every input file maps each page object's UTAM type path to
+//! its fully-qualified Rust path, so references resolve unambiguously and
+//! unknown ones are reported before any code is generated. The table can
+//! also record each page object's public members, so chained compose
+//! statements are checked against the component they are chained to.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use miette::{NamedSource, SourceSpan};
+
+use crate::ast::{CustomComponentRef, ElementAst, ElementTypeAst, PageObjectAst};
+use crate::error::{CompilerError, CompilerResult};
+use crate::location::pointer_span;
+use crate::utils::{module_ident, to_pascal_case};
+
+/// Page objects of a project, keyed by UTAM type path
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolTable {
+    types: BTreeMap<String, String>,
+    packages: BTreeSet<String>,
+    members: BTreeMap<String, Members>,
+}
+
+/// Public elements and methods of a page object
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Members {
+    /// Names of the public elements, including nested and shadow elements
+    pub elements: BTreeSet<String>,
+    /// Names of the compose methods
+    pub methods: BTreeSet<String>,
+}
+
+impl Members {
+    /// Collect the public members of a page object
+    pub fn of(ast: &PageObjectAst) -> Self {
+        let mut elements = BTreeSet::new();
+        collect_public(&ast.elements, &mut elements);
+        if let Some(shadow) = &ast.shadow {
+            collect_public(&shadow.elements, &mut elements);
+        }
+        let methods = ast.methods.iter().map(|method| method.name.clone()).collect();
+        Self { elements, methods }
+    }
+}
+
+impl SymbolTable {
+    /// Create an empty symbol table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a page object
+    ///
+    /// `dirs` are the directories between the package's `pageObjects` root
+    /// and the file, and `module` is the Rust module the package's generated
+    /// code is mounted at. `utam-lightning`, `[]`, `tree` and
+    /// `crate::utam_lightning` register `utam-lightning/pageObjects/tree` as
+    /// `crate::utam_lightning::tree::Tree`.
+    pub fn insert(&mut self, package: &str, dirs: &[String], name: &str, module: &str) {
+        let type_path = type_path(package, dirs, name);
+        let mut segments = vec![module.to_string()];
+        segments.extend(dirs.iter().map(|dir| module_ident(dir)));
+        segments.push(module_ident(name));
+        segments.push(to_pascal_case(name));
+        let rust_path = segments.join("::");
+        self.types.insert(type_path, rust_path);
+        self.packages.insert(package.to_string());
+    }
+
+    /// Register a page object along with its public members
+    pub fn insert_page_object(
+        &mut self,
+        package: &str,
+        dirs: &[String],
+        name: &str,
+        module: &str,
+        ast: &PageObjectAst,
+    ) {
+        self.insert(package, dirs, name, module);
+        self.members.insert(type_path(package, dirs, name), Members::of(ast));
+    }
+
+    /// Fully-qualified Rust path of the page object with this type path
+    pub fn resolve(&self, type_path: &str) -> Option<&str> {
+        self.types.get(type_path).map(String::as_str)
+    }
+
+    /// Public members of the page object with this type path, if known
+    pub fn members(&self, type_path: &str) -> Option<&Members> {
+        self.members.get(type_path)
+    }
+
+    /// Number of page objects in the table
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns true if the table has no page objects
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Check every custom component type in a page object resolves
+    ///
+    /// A type resolves when it is in the table, or when its package has no
+    /// page objects in the table and is one of `external_packages`
+    /// (compiled elsewhere and mapped to a Rust module).
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnresolvedComponent` for the first type that does not
+    /// resolve, labelled at the `type` value of its element in `source`
+    pub fn check_references(
+        &self,
+        ast: &PageObjectAst,
+        external_packages: &BTreeMap<String, String>,
+        name: &str,
+        source: &str,
+    ) -> CompilerResult<()> {
+        let mut references = Vec::new();
+        collect_references(&ast.elements, "/elements", &mut references);
+        if let Some(shadow) = &ast.shadow {
+            collect_references(&shadow.elements, "/shadow/elements", &mut references);
+        }
+
+        let unresolved = references.into_iter().find(|(type_path, package, _)| {
+            self.resolve(type_path).is_none()
+                && (self.packages.contains(package) || !external_packages.contains_key(package))
+        });
+        match unresolved {
+            Some((reference, _, pointer)) => {
+                let span = SourceSpan::from(pointer_span(source, &pointer).unwrap_or_default());
+                Err(CompilerError::UnresolvedComponent {
+                    reference,
+                    src: NamedSource::new(name, source.to_string()),
+                    span,
+                })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Type paths and packages of the custom components in `elements`,
+/// including nested and shadow elements, each with the JSON pointer of
+/// its element's `type`
+fn collect_references(
+    elements: &[ElementAst],
+    base: &str,
+    out: &mut Vec<(String, String, String)>,
+) {
+    for (i, element) in elements.iter().enumerate() {
+        let pointer = format!("{}/{}", base, i);
+        if let Some(ElementTypeAst::CustomComponent(type_path)) = &element.element_type {
+            let package = CustomComponentRef::parse(type_path).package;
+            out.push((type_path.clone(), package, format!("{}/type", pointer)));
+        }
+        collect_references(&element.elements, &format!("{}/elements", pointer), out);
+        if let Some(shadow) = &element.shadow {
+            collect_references(&shadow.elements, &format!("{}/shadow/elements", pointer), out);
+        }
+    }
+}
+
+/// Names of the public elements in `elements`, including nested and shadow
+/// elements
+fn collect_public(elements: &[ElementAst], out: &mut BTreeSet<String>) {
+    for element in elements {
+        if element.public {
+            out.insert(element.name.clone());
+        }
+        collect_public(&element.elements, out);
+        if let Some(shadow) = &element.shadow {
+            collect_public(&shadow.elements, out);
+        }
+    }
+}
+
+/// UTAM type path of a page object in a package
+fn type_path(package: &str, dirs: &[String], name: &str) -> String {
+    std::iter::once(package)
+        .chain(std::iter::once("pageObjects"))
+        .chain(dirs.iter().map(String::as_str))
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_object(json: &str) -> PageObjectAst {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const APP: &str = r#"{
+  "root": true,
+  "selector": { "css": "app-root" },
+  "elements": [
+    { "name": "tree", "type": "utam-lightning/pageObjects/tree", "selector": { "css": "lightning-tree" } },
+    { "name": "panel", "selector": { "css": ".panel" }, "shadow": { "elements": [
+      { "name": "saveButton", "type": "my-app/pageObjects/forms/save-button", "selector": { "css": ".save" } }
+    ]}}
+  ]
+}"#;
+
+    #[test]
+    fn test_insert_and_resolve() {
+        let mut symbols = SymbolTable::new();
+        symbols.insert("utam-lightning", &[], "tree", "crate::utam_lightning");
+        symbols.insert("my-app", &["forms".to_string()], "save-button", "crate::my_app");
+
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(
+            symbols.resolve("utam-lightning/pageObjects/tree"),
+            Some("crate::utam_lightning::tree::Tree")
+        );
+        assert_eq!(
+            symbols.resolve("my-app/pageObjects/forms/save-button"),
+            Some("crate::my_app::forms::save_button::SaveButton")
+        );
+        assert_eq!(symbols.resolve("utam-lightning/pageObjects/button"), None);
+    }
+
+    #[test]
+    fn test_insert_page_object_records_members() {
+        let ast = page_object(
+            r#"{
+  "root": true,
+  "selector": { "css": "app-root" },
+  "elements": [
+    { "name": "header", "selector": { "css": "header" }, "elements": [
+      { "name": "title", "public": true, "selector": { "css": "h1" } }
+    ]},
+    { "name": "footer", "selector": { "css": "footer" } }
+  ],
+  "methods": [{ "name": "getTitleText", "compose": [{ "element": "title", "apply": "getText" }] }]
+}"#,
+        );
+        let mut symbols = SymbolTable::new();
+        symbols.insert_page_object("my-app", &[], "app", "crate::my_app", &ast);
+
+        let members = symbols.members("my-app/pageObjects/app").unwrap();
+        assert_eq!(members.elements, BTreeSet::from(["title".to_string()]));
+        assert_eq!(members.methods, BTreeSet::from(["getTitleText".to_string()]));
+        assert_eq!(symbols.resolve("my-app/pageObjects/app"), Some("crate::my_app::app::App"));
+        assert!(symbols.members("my-app/pageObjects/other").is_none());
+    }
+
+    #[test]
+    fn test_check_references_resolved() {
+        let mut symbols = SymbolTable::new();
+        symbols.insert("my-app", &["forms".to_string()], "save-button", "crate::my_app");
+        let mut external = BTreeMap::new();
+        external.insert("utam-lightning".to_string(), "utam_lightning".to_string());
+
+        assert!(symbols.check_references(&page_object(APP), &external, "app", APP).is_ok());
+    }
+
+    #[test]
+    fn test_check_references_reports_span() {
+        let mut symbols = SymbolTable::new();
+        symbols.insert("utam-lightning", &[], "tree", "crate::utam_lightning");
+
+        let error =
+            symbols.check_references(&page_object(APP), &BTreeMap::new(), "app", APP).unwrap_err();
+        match error {
+            CompilerError::UnresolvedComponent { reference, span, .. } => {
+                assert_eq!(reference, "my-app/pageObjects/forms/save-button");
+                let start = span.offset();
+                assert_eq!(
+                    &APP[start..start + span.len()],
+                    "\"my-app/pageObjects/forms/save-button\""
+                );
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_references_span_is_the_type_value() {
+        let source = r#"{
+  "description": "Wraps \"my-app/pageObjects/card\" and \"my-app/pageObjects/card\"",
+  "root": true,
+  "selector": { "css": "app-root" },
+  "elements": [
+    { "name": "card", "type": "my-app/pageObjects/card", "selector": { "css": ".card" } }
+  ]
+}"#;
+        let symbols = SymbolTable::new();
+
+        let error = symbols
+            .check_references(&page_object(source), &BTreeMap::new(), "app", source)
+            .unwrap_err();
+        let CompilerError::UnresolvedComponent { span, .. } = error else {
+            panic!("unexpected error: {}", error);
+        };
+        assert_eq!(span.offset(), source.find("\"my-app/pageObjects/card\", \"selector").unwrap());
+        assert_eq!(span.len(), "\"my-app/pageObjects/card\"".len());
+    }
+
+    #[test]
+    fn test_check_references_in_mapped_project_package() {
+        let mut symbols = SymbolTable::new();
+        symbols.insert("my-app", &[], "tree", "crate::my_app");
+        let mut external = BTreeMap::new();
+        external.insert("utam-lightning".to_string(), "utam_lightning".to_string());
+        external.insert("my-app".to_string(), "crate::my_app".to_string());
+
+        let error = symbols.check_references(&page_object(APP), &external, "app", APP).unwrap_err();
+        assert!(matches!(
+            error,
+            CompilerError::UnresolvedComponent { reference, .. }
+                if reference == "my-app/pageObjects/forms/save-button"
+        ));
+    }
+}
@@ -9,7 +9,7 @@
 pub fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
     let mut prev_lowercase = false;
-    
+
     for (i, ch) in s.chars().enumerate() {
         if ch.is_uppercase() {
             if i > 0 && prev_lowercase {
@@ -22,7 +22,7 @@ pub fn to_snake_case(s: &str) -> String {
             prev_lowercase = ch.is_lowercase();
         }
     }
-    
+
     result
 }
 
@@ -33,7 +33,7 @@ pub fn to_snake_case(s: &str) -> String {
 pub fn to_pascal_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = true;
-    
+
     for ch in s.chars() {
         if ch == '_' || ch == '-' || ch == '/' || ch == '.' {
             capitalize_next = true;
@@ -44,10 +44,17 @@ pub fn to_pascal_case(s: &str) -> String {
             result.push(ch);
         }
     }
-    
+
     result
 }
 
+/// Convert a file or directory name into a Rust module identifier
+///
+/// Example: "login-form" -> "login_form", "saveButton" -> "save_button"
+pub fn module_ident(name: &str) -> String {
+    to_snake_case(name).replace(['-', '.', ' '], "_")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +76,11 @@ mod tests {
         assert_eq!(to_pascal_case("component"), "Component");
         assert_eq!(to_pascal_case("my-test.component"), "MyTestComponent");
     }
+
+    #[test]
+    fn test_module_ident() {
+        assert_eq!(module_ident("login-form"), "login_form");
+        assert_eq!(module_ident("saveButton"), "save_button");
+        assert_eq!(module_ident("my-test.component"), "my_test_component");
+    }
 }
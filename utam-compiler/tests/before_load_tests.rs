@@ -0,0 +1,162 @@
+//! Tests for `beforeLoad` statements run when a root page object loads
+
+mod common;
+
+use std::time::Duration;
+
+use common::*;
+use utam_compiler::{compile, CodeGenConfig};
+
+#[test]
+fn test_before_load_runs_before_root_lookup() {
+    let code = compile_squashed("load/before-load.utam.json");
+
+    assert!(code.contains(
+        "wait_for(||async{Self::before_load(driver).await?;letroot=driver.find(By::Css(Self::ROOT_SELECTOR)).await?;Ok(Some(Self::from_element(root).await?))},&config,\"pageobjecttoload\")"
+    ));
+    assert!(code.contains(
+        "asyncfnbefore_load(driver:&WebDriver)->UtamResult<()>{letdocument=Document::new(driver);letroot=||driver.find(By::Css(Self::ROOT_SELECTOR));"
+    ));
+}
+
+#[test]
+fn test_load_waits_for_default_timeout() {
+    let code = compile_squashed("load/before-load.utam.json");
+
+    assert!(code.contains(
+        "asyncfnload(driver:&WebDriver)->UtamResult<Self>{Self::wait_for_load(driver,WaitConfig::default().timeout).await}"
+    ));
+}
+
+#[test]
+fn test_before_load_waits_for_document() {
+    let code = compile_squashed("load/before-load.utam.json");
+
+    assert!(code.contains("document.wait_for_document_ready(&WaitConfig::default()).await?;"));
+    assert!(code.contains(
+        "letvalue=document.get_url().await?;if!(value.contains(\"/record/\")){returnErr(UtamError::AssertionFailed{"
+    ));
+}
+
+#[test]
+fn test_before_load_waits_for_root() {
+    let code = compile_squashed("load/before-load.utam.json");
+
+    assert!(code.contains(
+        "letvalue=ClickableElement::new(root().await?).is_visible().await?;Ok(value.then_some(true))"
+    ));
+}
+
+#[test]
+fn test_methods_use_root_element() {
+    let code = compile_squashed("load/before-load.utam.json");
+
+    assert!(code
+        .contains("letelement=ClickableElement::new(self.root.clone());element.click().await?;"));
+    assert!(code.contains(
+        "pubasyncfnis_root_focused(&self)->UtamResult<bool>{letresult=ClickableElement::new(self.root.clone()).is_focused().await?;Ok(result)}"
+    ));
+}
+
+#[test]
+fn test_function_predicate_argument() {
+    let code = compile_squashed("salesforce/salesforceStudioApp.utam.json");
+
+    assert!(code.contains("asyncfnbefore_load(driver:&WebDriver)->UtamResult<()>"));
+    assert!(code.contains("letvalue=BaseElement::new(root().await?).is_present().await?;"));
+}
+
+#[test]
+fn test_no_before_load_without_statements() {
+    let code = compile_squashed("basic/simple-element.utam.json");
+
+    assert!(!code.contains("before_load"));
+    assert!(code.contains(
+        "wait_for(||async{letroot=driver.find(By::Css(Self::ROOT_SELECTOR)).await?;Ok(Some(Self::from_element(root).await?))},&config,\"pageobjecttoload\").await"
+    ));
+}
+
+#[test]
+fn test_wait_for_load_uses_configured_wait() {
+    let json = include_str!("../../testdata/load/before-load.utam.json");
+    let config = CodeGenConfig {
+        module_name: Some("BeforeLoad".to_string()),
+        wait_timeout: Some(Duration::from_secs(20)),
+        poll_interval: Some(Duration::from_millis(250)),
+        ..Default::default()
+    };
+    let code: String = squash(&compile(json, config).expect("Failed to compile"));
+
+    assert!(code.contains(
+        "asyncfnload(driver:&WebDriver)->UtamResult<Self>{Self::wait_for_load(driver,std::time::Duration::from_millis(20000u64)"
+    ));
+    assert!(code.contains("asyncfnwait_for_load(driver:&WebDriver,timeout:std::time::Duration"));
+    assert!(code.contains("poll_interval:std::time::Duration::from_millis(250u64)"));
+    assert!(code.contains(
+        "document.wait_for_document_ready(&WaitConfig{timeout:std::time::Duration::from_millis(20000u64),poll_interval:std::time::Duration::from_millis(250u64),}).await?;"
+    ));
+}
+
+#[test]
+fn test_methods_reach_document_through_root() {
+    let code = compile_squashed("load/document-actions.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_page_url(&self)->UtamResult<String>{letdocument=Document::of(&self.root);letresult=document.get_url().await?;Ok(result)}"
+    ));
+}
+
+#[test]
+fn test_contains_element_takes_locator() {
+    let code = compile_squashed("load/document-actions.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnhas_toast(&self)->UtamResult<bool>{letdocument=Document::of(&self.root);letresult=document.contains_element(&Selector::Css(\".toast\".to_string())).await?;"
+    ));
+}
+
+#[test]
+fn test_enter_frame_and_load_returns_page_object() {
+    let code = compile_squashed("load/document-actions.utam.json");
+
+    assert!(code.contains("pubasyncfnload_content(&self)->UtamResult<ContentPage>"));
+    assert!(code.contains(
+        "letresult=document.enter_frame_and_load::<ContentPage>(&self.get_content_frame().await?).await?;"
+    ));
+}
+
+#[test]
+fn test_enter_frame_and_load_requires_page_object() {
+    let json = r#"{
+  "root": true,
+  "selector": { "css": "app-shell" },
+  "elements": [{ "name": "contentFrame", "type": "frame", "selector": { "css": "iframe" } }],
+  "methods": [
+    {
+      "name": "loadContent",
+      "compose": [
+        {
+          "element": "document",
+          "apply": "enterFrameAndLoad",
+          "args": [{ "value": "contentFrame", "type": "frameElement" }]
+        }
+      ]
+    }
+  ]
+}"#;
+    let error = compile(json, CodeGenConfig::default()).unwrap_err();
+    assert!(error.to_string().contains("enterFrameAndLoad"));
+}
+
+#[test]
+fn test_unknown_document_action_is_rejected() {
+    let json = r#"{
+  "root": true,
+  "selector": { "css": "record-page" },
+  "methods": [
+    { "name": "isPageReady", "compose": [{ "element": "document", "apply": "isReady" }] }
+  ]
+}"#;
+    let error = compile(json, CodeGenConfig::default()).unwrap_err();
+    assert!(error.to_string().contains("'isReady' to document, which is not a document action"));
+}
@@ -0,0 +1,155 @@
+//! Tests for compose statements chained to the previous statement's result
+
+mod common;
+
+use std::sync::Arc;
+
+use common::*;
+use utam_compiler::{compile, CodeGenConfig, CompilerError, PageObjectAst, SymbolTable};
+
+/// Symbol table of package `my-app` with the members of the chain dialog
+fn dialog_symbols() -> Arc<SymbolTable> {
+    let dialog: PageObjectAst = serde_json::from_str(&load_fixture("chain/dialog.utam.json"))
+        .expect("Failed to parse dialog");
+    let mut symbols = SymbolTable::new();
+    symbols.insert_page_object("my-app", &[], "dialog", "crate::my_app", &dialog);
+    symbols.insert("my-app", &[], "checkout", "crate::my_app");
+    Arc::new(symbols)
+}
+
+fn compile_with_dialog(json: &str) -> Result<String, utam_compiler::CompilerError> {
+    compile(
+        json,
+        CodeGenConfig {
+            module_name: Some("Checkout".to_string()),
+            symbols: Some(dialog_symbols()),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn test_chain_element_of_custom_component() {
+    let code = compile_squashed("chain/checkout.utam.json");
+
+    assert!(code.contains(
+        "letprevious=self.get_dialog().await?;previous.get_ok_button().await?.click().await?;Ok(())"
+    ));
+}
+
+#[test]
+fn test_chain_method_of_custom_component() {
+    let code = compile_squashed("chain/checkout.utam.json");
+
+    assert!(code.contains("letprevious=self.get_dialog().await?;previous.accept().await?;Ok(())"));
+}
+
+#[test]
+fn test_chain_through_return_type() {
+    let code = compile_squashed("chain/checkout.utam.json");
+
+    assert!(code.contains(
+        "letprevious=self.get_dialog().await?;letprevious=previous.get_title().await?;letresult=previous.get_text().await?;Ok(result)"
+    ));
+}
+
+#[test]
+fn test_chain_action_of_basic_element() {
+    let code = compile_squashed("chain/checkout.utam.json");
+
+    assert!(code.contains("pubasyncfnget_total_text(&self)->UtamResult<String>"));
+    assert!(code.contains(
+        "letprevious=self.get_total().await?;letresult=previous.get_text().await?;Ok(result)"
+    ));
+}
+
+#[test]
+fn test_chain_through_return_type_infers_action_type() {
+    let code = compile_squashed("chain/checkout.utam.json");
+
+    assert!(code.contains("pubasyncfnget_dialog_title(&self)->UtamResult<String>"));
+    assert!(code.contains("pubasyncfnaccept_dialog(&self)->UtamResult<()>"));
+}
+
+#[test]
+fn test_chain_of_unknown_type_requires_return_type() {
+    let error = compile_fixture("invalid/unknown-chain-return.utam.json").unwrap_err();
+    assert!(matches!(
+        error,
+        CompilerError::UnknownReturnType { ref method } if method == "acceptDialog"
+    ));
+}
+
+#[test]
+fn test_chain_to_value_is_rejected() {
+    let error = compile_fixture("invalid/chain-to-value.utam.json").unwrap_err();
+    assert!(error.to_string().contains("is chained to a 'string' value"));
+}
+
+#[test]
+fn test_apply_without_element_calls_own_method() {
+    let code = compile_squashed("compose/chained-method.utam.json");
+
+    assert!(code.contains("self.perform_search(query).await?;"));
+    assert!(!code.contains("self.root.perform_search"));
+}
+
+#[test]
+fn test_method_named_like_getter_is_rejected() {
+    let error = compile_fixture("invalid/method-name-collision.utam.json").unwrap_err();
+    assert!(matches!(
+        error,
+        CompilerError::NameCollision { ref method, ref element, ref name }
+            if method == "getTotal" && element == "total" && name == "get_total"
+    ));
+}
+
+#[test]
+fn test_chain_unknown_action_of_basic_element_is_rejected() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "checkout-page" },
+        "elements": [{ "name": "total", "selector": { "css": ".total" } }],
+        "methods": [{ "name": "typeTotal", "compose": [
+            { "element": "total" },
+            { "chain": true, "apply": "setText", "args": ["42"] }
+        ]}]
+    }"#;
+
+    let error = compile(json, CodeGenConfig::default()).unwrap_err();
+    assert!(error.to_string().contains("'setText', which is not an action of a basic element"));
+}
+
+#[test]
+fn test_chain_checked_against_component_members() {
+    let json = load_fixture("chain/checkout.utam.json");
+    let code = compile_with_dialog(&json).expect("Failed to compile");
+    assert!(code.contains("crate::my_app::dialog::Dialog"));
+
+    let missing_element = json.replace("\"okButton\"", "\"cancelButton\"");
+    let error = compile_with_dialog(&missing_element).unwrap_err();
+    assert!(error.to_string().contains(
+        "chains element 'cancelButton', which 'my-app/pageObjects/dialog' does not expose"
+    ));
+
+    let missing_method = json.replace("\"accept\"", "\"dismiss\"");
+    let error = compile_with_dialog(&missing_method).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("chains method 'dismiss', which 'my-app/pageObjects/dialog' does not declare"));
+}
+
+#[test]
+fn test_first_statement_cannot_be_chained() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "checkout-page" },
+        "elements": [{ "name": "total", "selector": { "css": ".total" } }],
+        "methods": [{ "name": "readTotal", "compose": [
+            { "chain": true, "element": "total", "apply": "getText" }
+        ]}]
+    }"#;
+
+    let error = compile(json, CodeGenConfig::default()).unwrap_err();
+    assert!(error.to_string().contains("is chained but has no previous statement"));
+}
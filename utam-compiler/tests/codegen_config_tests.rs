@@ -0,0 +1,135 @@
+//! Tests for code generation settings in `CodeGenConfig`
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use utam_compiler::{compile, CodeGenConfig, CompilerError, SymbolTable};
+
+const CUSTOM_COMPONENTS: &str = r#"{
+    "root": true,
+    "selector": { "css": "app-root" },
+    "elements": [
+        {
+            "name": "tree",
+            "type": "utam-lightning/pageObjects/tree",
+            "selector": { "css": "lightning-tree" },
+            "public": true
+        },
+        {
+            "name": "submit",
+            "type": "my-app/pageObjects/forms/submit-button",
+            "selector": { "css": ".submit" },
+            "public": true
+        }
+    ]
+}"#;
+
+fn config(module_name: &str) -> CodeGenConfig {
+    CodeGenConfig { module_name: Some(module_name.to_string()), ..Default::default() }
+}
+
+#[test]
+fn test_mapped_package_uses_module_path() {
+    let mut package_modules = BTreeMap::new();
+    package_modules.insert("utam-lightning".to_string(), "utam_lightning::pageobjects".to_string());
+    package_modules.insert("my-app".to_string(), "crate::generated".to_string());
+
+    let code = compile(CUSTOM_COMPONENTS, CodeGenConfig { package_modules, ..config("App") })
+        .expect("Failed to compile");
+
+    assert!(code.contains("utam_lightning::pageobjects::Tree"));
+    assert!(code.contains("crate::generated::forms::SubmitButton"));
+}
+
+#[test]
+fn test_mapped_package_directories_match_module_names() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "app-root" },
+        "elements": [
+            { "name": "card", "type": "my-app/pageObjects/v1.2/cards/card", "selector": { "css": ".card" } }
+        ]
+    }"#;
+    let mut package_modules = BTreeMap::new();
+    package_modules.insert("my-app".to_string(), "crate::generated".to_string());
+
+    let code = compile(json, CodeGenConfig { package_modules, ..config("App") })
+        .expect("Failed to compile");
+
+    assert!(code.contains("crate::generated::v1_2::cards::Card::from_element"));
+}
+
+#[test]
+fn test_unmapped_package_uses_struct_name() {
+    let code = compile(CUSTOM_COMPONENTS, config("App")).expect("Failed to compile");
+
+    assert!(code.contains("Tree::from_element"));
+    assert!(!code.contains("utam_lightning::"));
+}
+
+#[test]
+fn test_symbols_resolve_to_full_path() {
+    let mut symbols = SymbolTable::new();
+    symbols.insert("my-app", &["forms".to_string()], "submit-button", "crate::my_app");
+    let mut package_modules = BTreeMap::new();
+    package_modules.insert("utam-lightning".to_string(), "utam_lightning::pageobjects".to_string());
+
+    let code = compile(
+        CUSTOM_COMPONENTS,
+        CodeGenConfig { package_modules, symbols: Some(Arc::new(symbols)), ..config("App") },
+    )
+    .expect("Failed to compile");
+
+    assert!(code.contains("crate::my_app::forms::submit_button::SubmitButton::from_element"));
+    assert!(code.contains("utam_lightning::pageobjects::Tree::from_element"));
+}
+
+#[test]
+fn test_unresolved_component_is_reported() {
+    let mut symbols = SymbolTable::new();
+    symbols.insert("my-app", &["forms".to_string()], "submit-button", "crate::my_app");
+
+    let error = compile(
+        CUSTOM_COMPONENTS,
+        CodeGenConfig { symbols: Some(Arc::new(symbols)), ..config("App") },
+    )
+    .unwrap_err();
+
+    let CompilerError::UnresolvedComponent { reference, span, .. } = error else {
+        panic!("Expected unresolved component, got {:?}", error);
+    };
+    assert_eq!(reference, "utam-lightning/pageObjects/tree");
+    let start = span.offset();
+    assert_eq!(
+        &CUSTOM_COMPONENTS[start..start + span.len()],
+        "\"utam-lightning/pageObjects/tree\""
+    );
+}
+
+#[test]
+fn test_wait_timeout_waits_for_root() {
+    let json = r#"{ "root": true, "selector": { "css": ".page" } }"#;
+    let code = compile(
+        json,
+        CodeGenConfig {
+            wait_timeout: Some(Duration::from_secs(5)),
+            poll_interval: Some(Duration::from_millis(100)),
+            ..config("Page")
+        },
+    )
+    .expect("Failed to compile");
+
+    assert!(code.contains("Duration::from_millis(5000u64)"));
+    assert!(code.contains("poll_interval: std::time::Duration::from_millis(100u64)"));
+    assert!(code.contains("page object to load"));
+}
+
+#[test]
+fn test_no_wait_timeout_waits_for_root_with_default_timeout() {
+    let json = r#"{ "root": true, "selector": { "css": ".page" } }"#;
+    let code = compile(json, config("Page")).expect("Failed to compile");
+
+    assert!(code.contains("Self::wait_for_load(driver, WaitConfig::default().timeout).await"));
+    assert!(code.contains("page object to load"));
+}
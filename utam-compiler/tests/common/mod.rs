@@ -1,17 +1,18 @@
 //! Test utilities for UTAM compiler integration tests
 //!
 //! Provides common helpers for testing UTAM JSON compilation.
+//!
+//! Each test crate includes this module and uses only some of its helpers.
+
+#![allow(dead_code)]
 
 use std::path::Path;
-use utam_compiler::{compile, CodeGenConfig, CompilerResult, utils::to_pascal_case};
+use utam_compiler::{compile, utils::to_pascal_case, CodeGenConfig, CompilerResult};
 
 /// Load a test fixture from the testdata directory
 pub fn load_fixture(path: &str) -> String {
-    let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .parent()
-        .unwrap()
-        .join("testdata")
-        .join(path);
+    let fixture_path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("testdata").join(path);
     std::fs::read_to_string(&fixture_path)
         .unwrap_or_else(|e| panic!("Failed to load fixture {}: {}", fixture_path.display(), e))
 }
@@ -21,22 +22,38 @@ pub fn compile_fixture(path: &str) -> CompilerResult<String> {
     let json = load_fixture(path);
     // Extract module name from path (e.g., "basic/simple-element.utam.json" -> "SimpleElement")
     let module_name = extract_module_name(path);
-    let config = CodeGenConfig {
-        module_name: Some(module_name),
-    };
+    let config = CodeGenConfig { module_name: Some(module_name), ..Default::default() };
     compile(&json, config)
 }
 
+/// Remove all whitespace from generated code
+///
+/// Trailing commas that prettyplease adds when it wraps an argument or
+/// generic list are removed too, so assertions do not depend on how long
+/// lines are wrapped.
+pub fn squash(code: &str) -> String {
+    let code: String = code.split_whitespace().collect();
+    code.replace(",)", ")").replace(",>", ">")
+}
+
+/// Compile a test fixture and squash the generated code
+pub fn compile_squashed(path: &str) -> String {
+    squash(&compile_fixture(path).expect("Failed to compile"))
+}
+
+/// Compile inline JSON as the named module and squash the generated code
+pub fn compile_json_squashed(json: &str, module_name: &str) -> String {
+    let config = CodeGenConfig { module_name: Some(module_name.to_string()), ..Default::default() };
+    squash(&compile(json, config).expect("Failed to compile"))
+}
+
 /// Extract module name from fixture path
 fn extract_module_name(path: &str) -> String {
-    let filename = Path::new(path)
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("PageObject");
-    
+    let filename = Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or("PageObject");
+
     // Remove .utam suffix if present
     let name = filename.strip_suffix(".utam").unwrap_or(filename);
-    
+
     // Convert to PascalCase using shared utility
     to_pascal_case(name)
 }
@@ -45,7 +62,7 @@ fn extract_module_name(path: &str) -> String {
 #[track_caller]
 pub fn assert_compiles(path: &str) {
     match compile_fixture(path) {
-        Ok(_code) => {},
+        Ok(_code) => {}
         Err(e) => panic!("Expected fixture {} to compile successfully, but got error: {}", path, e),
     }
 }
@@ -53,9 +70,8 @@ pub fn assert_compiles(path: &str) {
 /// Assert that compilation fails for a given fixture
 #[track_caller]
 pub fn assert_fails_to_compile(path: &str) {
-    match compile_fixture(path) {
-        Ok(_) => panic!("Expected fixture {} to fail compilation, but it succeeded", path),
-        Err(_) => {},
+    if compile_fixture(path).is_ok() {
+        panic!("Expected fixture {} to fail compilation, but it succeeded", path);
     }
 }
 
@@ -21,6 +21,11 @@ fn test_compile_editable_input() {
     assert_compiles("basic/editable-input.utam.json");
 }
 
+#[test]
+fn test_compile_nullable_element() {
+    assert_compiles("basic/nullable-element.utam.json");
+}
+
 #[test]
 fn test_compile_shadow_root() {
     assert_compiles("shadow-dom/shadow-root.utam.json");
@@ -31,6 +36,11 @@ fn test_compile_nested_shadow() {
     assert_compiles("shadow-dom/nested-shadow.utam.json");
 }
 
+#[test]
+fn test_compile_nested_elements() {
+    assert_compiles("shadow-dom/nested-elements.utam.json");
+}
+
 #[test]
 fn test_compile_simple_method() {
     assert_compiles("compose/simple-method.utam.json");
@@ -46,11 +56,92 @@ fn test_compile_filter_method() {
     assert_compiles("compose/filter-method.utam.json");
 }
 
+#[test]
+fn test_compile_nested_directory() {
+    assert_compiles("nested/app-shell.utam.json");
+    assert_compiles("nested/forms/login-form.utam.json");
+}
+
 #[test]
 fn test_compile_salesforce_app() {
     assert_compiles("salesforce/salesforceStudioApp.utam.json");
 }
 
+#[test]
+fn test_compile_filtered_list() {
+    assert_compiles("filter/filtered-list.utam.json");
+}
+
+#[test]
+fn test_compile_interface() {
+    assert_compiles("interface/navigation.utam.json");
+}
+
+#[test]
+fn test_compile_interface_implementation() {
+    assert_compiles("interface/desktop-navigation.utam.json");
+}
+
+#[test]
+fn test_compile_chain_component() {
+    assert_compiles("chain/dialog.utam.json");
+}
+
+#[test]
+fn test_compile_chained_statements() {
+    assert_compiles("chain/checkout.utam.json");
+}
+
+#[test]
+fn test_compile_wait_for_predicate() {
+    assert_compiles("wait/wait-for-predicate.utam.json");
+}
+
+#[test]
+fn test_compile_matcher_assertions() {
+    assert_compiles("matcher/assertions.utam.json");
+}
+
+#[test]
+fn test_compile_inferred_return_types() {
+    assert_compiles("return-types/inferred-returns.utam.json");
+}
+
+#[test]
+fn test_compile_before_load() {
+    assert_compiles("load/before-load.utam.json");
+}
+
+#[test]
+fn test_compile_document_actions() {
+    assert_compiles("load/document-actions.utam.json");
+}
+
+#[test]
+fn test_compile_eager_elements() {
+    assert_compiles("load/eager-elements.utam.json");
+}
+
+#[test]
+fn test_compile_editor_frame() {
+    assert_compiles("frame/editor-frame.utam.json");
+}
+
+#[test]
+fn test_compile_dialog_host() {
+    assert_compiles("container/dialog-host.utam.json");
+}
+
+#[test]
+fn test_compile_native_selectors() {
+    assert_compiles("mobile/native-selectors.utam.json");
+}
+
+#[test]
+fn test_compile_touch_list() {
+    assert_compiles("mobile/touch-list.utam.json");
+}
+
 #[test]
 fn test_invalid_missing_selector() {
     // Missing selector should still parse, but won't generate valid code
@@ -64,3 +155,58 @@ fn test_invalid_type() {
     // Type checking happens at Rust compile time
     assert_compiles("invalid/invalid-type.utam.json");
 }
+
+#[test]
+fn test_invalid_unsupported_matcher() {
+    assert_fails_to_compile("invalid/unsupported-matcher.utam.json");
+}
+
+#[test]
+fn test_invalid_unsupported_selector() {
+    assert_fails_to_compile("invalid/unsupported-selector.utam.json");
+}
+
+#[test]
+fn test_invalid_chain_to_value() {
+    assert_fails_to_compile("invalid/chain-to-value.utam.json");
+}
+
+#[test]
+fn test_invalid_method_name_collision() {
+    assert_fails_to_compile("invalid/method-name-collision.utam.json");
+}
+
+#[test]
+fn test_invalid_unsupported_assertion() {
+    assert_fails_to_compile("invalid/unsupported-assertion.utam.json");
+}
+
+#[test]
+fn test_invalid_return_type_mismatch() {
+    assert_fails_to_compile("invalid/return-type-mismatch.utam.json");
+}
+
+#[test]
+fn test_invalid_unknown_chain_return() {
+    assert_fails_to_compile("invalid/unknown-chain-return.utam.json");
+}
+
+#[test]
+fn test_invalid_nested_frames() {
+    assert_fails_to_compile("invalid/nested-frames.utam.json");
+}
+
+#[test]
+fn test_invalid_list_container() {
+    assert_fails_to_compile("invalid/list-container.utam.json");
+}
+
+#[test]
+fn test_invalid_parameterized_load() {
+    assert_fails_to_compile("invalid/parameterized-load.utam.json");
+}
+
+#[test]
+fn test_invalid_non_root_load() {
+    assert_fails_to_compile("invalid/non-root-load.utam.json");
+}
@@ -55,22 +55,10 @@ fn test_method_signature_with_multiple_args() {
         name: "submitForm".to_string(),
         description: None,
         args: vec![
-            MethodArgAst {
-                name: "firstName".to_string(),
-                arg_type: "string".to_string(),
-            },
-            MethodArgAst {
-                name: "lastName".to_string(),
-                arg_type: "string".to_string(),
-            },
-            MethodArgAst {
-                name: "age".to_string(),
-                arg_type: "number".to_string(),
-            },
-            MethodArgAst {
-                name: "isActive".to_string(),
-                arg_type: "boolean".to_string(),
-            },
+            MethodArgAst { name: "firstName".to_string(), arg_type: "string".to_string() },
+            MethodArgAst { name: "lastName".to_string(), arg_type: "string".to_string() },
+            MethodArgAst { name: "age".to_string(), arg_type: "number".to_string() },
+            MethodArgAst { name: "isActive".to_string(), arg_type: "boolean".to_string() },
         ],
         compose: vec![],
         return_type: None,
@@ -88,30 +76,26 @@ fn test_method_signature_with_multiple_args() {
 
 #[test]
 fn test_resolve_element_reference() {
-    let statements = vec![
-        ComposeStatementAst {
-            element: Some("usernameInput".to_string()),
-            apply: Some("clearAndType".to_string()),
-            args: vec![ComposeArgAst::Named {
-                name: "username".to_string(),
-                arg_type: "argumentReference".to_string(),
-            }],
-            chain: false,
-            return_type: None,
-            return_all: false,
-            matcher: None,
-            apply_external: None,
-            filter: None,
-            return_element: false,
-            predicate: None,
-        },
-    ];
-
-    let method_args = vec![MethodArgAst {
-        name: "username".to_string(),
-        arg_type: "string".to_string(),
+    let statements = vec![ComposeStatementAst {
+        element: Some("usernameInput".to_string()),
+        apply: Some("clearAndType".to_string()),
+        args: vec![ComposeArgAst::Named {
+            name: "username".to_string(),
+            arg_type: "argumentReference".to_string(),
+        }],
+        chain: false,
+        return_type: None,
+        return_all: false,
+        matcher: None,
+        apply_external: None,
+        filter: None,
+        return_element: false,
+        predicate: None,
     }];
 
+    let method_args =
+        vec![MethodArgAst { name: "username".to_string(), arg_type: "string".to_string() }];
+
     let compiled = compile_compose_statements(&statements, &method_args, &[]).unwrap();
     assert_eq!(compiled.len(), 1);
 
@@ -149,10 +133,8 @@ fn test_handle_argument_reference_not_found() {
         predicate: None,
     }];
 
-    let method_args = vec![MethodArgAst {
-        name: "username".to_string(),
-        arg_type: "string".to_string(),
-    }];
+    let method_args =
+        vec![MethodArgAst { name: "username".to_string(), arg_type: "string".to_string() }];
 
     let result = compile_compose_statements(&statements, &method_args, &[]);
     assert!(result.is_err());
@@ -233,7 +215,7 @@ fn test_matcher_contains() {
         StatementKind::MatcherAssert { matcher, value } => {
             assert_eq!(*matcher, MatcherKind::Contains);
             match value {
-                CompiledArg::Literal(s) => assert_eq!(s, "\"test\""),
+                Some(CompiledArg::Literal(s)) => assert_eq!(s, "\"test\""),
                 _ => panic!("Expected Literal"),
             }
         }
@@ -325,6 +307,42 @@ fn test_matcher_ends_with() {
     }
 }
 
+#[test]
+fn test_matcher_without_argument() {
+    let statements = vec![ComposeStatementAst {
+        element: None,
+        apply: None,
+        args: vec![],
+        chain: false,
+        return_type: None,
+        return_all: false,
+        matcher: Some(MatcherAst { matcher_type: "isTrue".to_string(), args: vec![] }),
+        apply_external: None,
+        filter: None,
+        return_element: false,
+        predicate: None,
+    }];
+
+    let compiled = compile_compose_statements(&statements, &[], &[]).unwrap();
+    match &compiled[0].kind {
+        StatementKind::MatcherAssert { matcher, value } => {
+            assert_eq!(*matcher, MatcherKind::IsTrue);
+            assert!(value.is_none());
+        }
+        _ => panic!("Expected MatcherAssert"),
+    }
+}
+
+#[test]
+fn test_matcher_kind_parse() {
+    assert_eq!(MatcherKind::parse("stringContains"), Some(MatcherKind::Contains));
+    assert_eq!(MatcherKind::parse("stringEqualsIgnoreCase"), Some(MatcherKind::EqualsIgnoreCase));
+    assert_eq!(MatcherKind::parse("notNull"), Some(MatcherKind::NotNull));
+    assert_eq!(MatcherKind::parse("stringMatchesRegex"), None);
+    assert!(MatcherKind::Equals.takes_arg());
+    assert!(!MatcherKind::IsFalse.takes_arg());
+}
+
 #[test]
 fn test_matcher_invalid_type() {
     let statements = vec![ComposeStatementAst {
@@ -357,10 +375,7 @@ fn test_matcher_missing_argument() {
         chain: false,
         return_type: None,
         return_all: false,
-        matcher: Some(MatcherAst {
-            matcher_type: "contains".to_string(),
-            args: vec![],
-        }),
+        matcher: Some(MatcherAst { matcher_type: "contains".to_string(), args: vec![] }),
         apply_external: None,
         filter: None,
         return_element: false,
@@ -373,25 +388,23 @@ fn test_matcher_missing_argument() {
 
 #[test]
 fn test_literal_arguments_types() {
-    let statements = vec![
-        ComposeStatementAst {
-            element: Some("input".to_string()),
-            apply: Some("setText".to_string()),
-            args: vec![
-                ComposeArgAst::Value(serde_json::json!("string value")),
-                ComposeArgAst::Value(serde_json::json!(42)),
-                ComposeArgAst::Value(serde_json::json!(true)),
-            ],
-            chain: false,
-            return_type: None,
-            return_all: false,
-            matcher: None,
-            apply_external: None,
-            filter: None,
-            return_element: false,
-            predicate: None,
-        },
-    ];
+    let statements = vec![ComposeStatementAst {
+        element: Some("input".to_string()),
+        apply: Some("setText".to_string()),
+        args: vec![
+            ComposeArgAst::Value(serde_json::json!("string value")),
+            ComposeArgAst::Value(serde_json::json!(42)),
+            ComposeArgAst::Value(serde_json::json!(true)),
+        ],
+        chain: false,
+        return_type: None,
+        return_all: false,
+        matcher: None,
+        apply_external: None,
+        filter: None,
+        return_element: false,
+        predicate: None,
+    }];
 
     let compiled = compile_compose_statements(&statements, &[], &[]).unwrap();
     match &compiled[0].kind {
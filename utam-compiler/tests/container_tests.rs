@@ -0,0 +1,74 @@
+//! Tests for container elements loading arbitrary page objects
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_container_getter_uses_default_selector() {
+    let code = compile_squashed("container/dialog-host.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_body_container<T:PageObject>(&self)->UtamResult<Container<T>>{letshadow=self.root.get_shadow_root().await?;Ok(Container::new(shadow))}"
+    ));
+    assert!(!code.contains("ContainerElement"));
+}
+
+#[test]
+fn test_container_getter_loads_page_object() {
+    let code = compile_squashed("container/dialog-host.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_body<T:RootPageObject>(&self)->UtamResult<T>{self.get_body_container::<T>().await?.load().await}"
+    ));
+}
+
+#[test]
+fn test_nullable_container_with_selector() {
+    let code = compile_squashed("container/dialog-host.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_actions_container<T:PageObject>(&self)->UtamResult<Option<Container<T>>>"
+    ));
+    assert!(code.contains(
+        "letcontainer=Container::new(parent).with_selector(By::Css(\"slot[name='actions']\"));"
+    ));
+    assert!(code.contains(
+        "pubasyncfnget_actions<T:RootPageObject>(&self)->UtamResult<Option<T>>{matchself.get_actions_container::<T>().await?{Some(container)=>Ok(Some(container.load().await?)),None=>Ok(None),}}"
+    ));
+}
+
+#[test]
+fn test_nullable_container_is_none_only_when_itself_missing() {
+    let code = compile_squashed("container/dialog-host.utam.json");
+    let getter = code
+        .split("pubasyncfnget_actions_container<T:PageObject>")
+        .nth(1)
+        .and_then(|rest| rest.split("pubasyncfn").next())
+        .expect("Missing actions container getter");
+
+    // A missing footer is an error rather than an absent container
+    assert!(getter.contains("letparent=shadow.find(By::Css(\".footer\")).await?;"));
+    assert!(!getter.contains("find_all(By::Css(\".footer\"))"));
+    // Only an empty actions slot gives `None`
+    assert!(getter.contains("if!container.is_present().await?{returnOk(None);}Ok(Some(container))"));
+    assert_eq!(getter.matches("returnOk(None)").count(), 1);
+}
+
+#[test]
+fn test_container_wait_and_method_are_generic() {
+    let code = compile_squashed("container/dialog-host.utam.json");
+
+    assert!(code
+        .contains("pubasyncfnwait_for_body<T:RootPageObject>(&self,timeout:std::time::Duration)"));
+    assert!(code.contains("matchself.get_body::<T>().await{"));
+    assert!(code.contains(
+        "pubasyncfnget_body_content<T:RootPageObject>(&self)->UtamResult<T>{letresult=self.get_body().await?;Ok(result)}"
+    ));
+}
+
+#[test]
+fn test_list_container_is_rejected() {
+    let error = compile_fixture("invalid/list-container.utam.json").unwrap_err();
+    assert!(error.to_string().contains("Invalid container 'panels'"));
+}
@@ -0,0 +1,74 @@
+//! Tests for elements declaring several action types
+
+mod common;
+
+use common::*;
+
+/// Page object with a single element of the given action types, clicked
+/// and typed into by a method
+fn form_with_field(types: &str) -> String {
+    format!(
+        r#"{{
+  "root": true,
+  "selector": {{ "css": "form" }},
+  "elements": [
+    {{ "name": "field", "type": {}, "selector": {{ "css": "input" }}, "public": true }}
+  ],
+  "methods": [
+    {{
+      "name": "fill",
+      "compose": [
+        {{ "element": "field", "apply": "click" }},
+        {{ "element": "field", "apply": "setText", "args": [{{ "name": "text", "type": "string" }}] }}
+      ]
+    }}
+  ]
+}}"#,
+        types
+    )
+}
+
+#[test]
+fn test_several_action_types_union_capabilities() {
+    let code = compile_json_squashed(&form_with_field(r#"["clickable", "editable"]"#), "Form");
+
+    assert!(code.contains("pubasyncfnget_field(&self)->UtamResult<Element<(Click,Edit)>>"));
+    assert!(code.contains("letwrapped=Element::<(Click,Edit)>::new(elem);"));
+    assert!(code.contains("element.click().await?;"));
+    assert!(code.contains(".set_text(text).await?;"));
+}
+
+#[test]
+fn test_capabilities_do_not_depend_on_declared_order() {
+    let code = compile_json_squashed(
+        &form_with_field(r#"["touchable", "editable", "clickable"]"#),
+        "Form",
+    );
+
+    assert!(code.contains("UtamResult<Element<(Click,Edit,Touch)>>"));
+}
+
+#[test]
+fn test_single_action_type_uses_alias() {
+    let code = compile_json_squashed(&form_with_field(r#"["actionable", "editable"]"#), "Form");
+
+    assert!(code.contains("pubasyncfnget_field(&self)->UtamResult<EditableElement>"));
+    assert!(code.contains("letwrapped=EditableElement::new(elem);"));
+}
+
+#[test]
+fn test_root_with_several_action_types() {
+    let code = compile_json_squashed(
+        r#"{
+  "root": true,
+  "selector": { "css": "my-card" },
+  "type": ["clickable", "touchable"],
+  "exposeRootElement": true
+}"#,
+        "Form",
+    );
+
+    assert!(code.contains(
+        "pubasyncfnget_root(&self)->UtamResult<Element<(Click,Touch)>>{Ok(Element::<(Click,Touch)>::new(self.root.clone()))}"
+    ));
+}
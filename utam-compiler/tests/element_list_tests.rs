@@ -0,0 +1,59 @@
+//! Tests for list elements returned as an `ElementList`
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_list_getter_keeps_query() {
+    let code = compile_squashed("filter/filtered-list.utam.json");
+
+    assert!(code.contains(
+        "letroot=self.root.clone();lettext=text.to_owned();ElementList::query(move||{letroot=root.clone();lettext=text.clone();asyncmove{lettext=text.as_str();letshadow=root.get_shadow_root().await?;"
+    ));
+    assert!(code.contains("Ok(result)}}).await}"));
+}
+
+#[test]
+fn test_list_getter_without_arguments() {
+    let code = compile_json_squashed(
+        r#"{
+  "root": true,
+  "selector": { "css": "data-grid" },
+  "elements": [
+    { "name": "rows", "selector": { "css": "tr" }, "list": true, "public": true }
+  ]
+}"#,
+        "Grid",
+    );
+
+    assert!(code.contains("pubasyncfnget_rows(&self)->UtamResult<ElementList<BaseElement>>"));
+    assert!(code.contains(
+        "ElementList::query(move||{letroot=root.clone();asyncmove{letelements=root.find_all(By::Css(\"tr\")).await?;"
+    ));
+}
+
+#[test]
+fn test_list_in_frame_is_fixed() {
+    let code = compile_json_squashed(
+        r#"{
+  "root": true,
+  "selector": { "css": "body" },
+  "elements": [
+    {
+      "name": "gridFrame",
+      "type": "frame",
+      "selector": { "css": "iframe.grid" },
+      "elements": [
+        { "name": "cells", "selector": { "css": "td" }, "list": true, "public": true }
+      ]
+    }
+  ]
+}"#,
+        "Grid",
+    );
+
+    assert!(code.contains("UtamResult<Framed<ElementList<BaseElement>>>"));
+    assert!(code.contains("letitems:UtamResult<Vec<BaseElement>>=async{"));
+    assert!(code.contains("letvalue=items.map(ElementList::new);"));
+}
@@ -0,0 +1,95 @@
+//! Tests for getters of list elements with a `filter`
+
+mod common;
+
+use common::*;
+use utam_compiler::{compile, CodeGenConfig};
+
+#[test]
+fn test_filter_keeps_matching_items() {
+    let code = compile_squashed("filter/filtered-list.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_items_with_text(&self,text:&str)->UtamResult<ElementList<ClickableElement>>"
+    ));
+    assert!(code.contains(
+        "letvalue=wrapped.get_text().await?;ifvalue.contains(text){result.push(wrapped);}"
+    ));
+}
+
+#[test]
+fn test_find_first_returns_single_element() {
+    let code = compile_squashed("filter/filtered-list.utam.json");
+
+    assert!(code
+        .contains("pubasyncfnget_item_by_label(&self,label:&str)->UtamResult<ClickableElement>"));
+    assert!(code.contains("ifvalue==label{returnOk(wrapped);}"));
+    assert!(code.contains(
+        "Err(UtamError::ElementNotFound{name:\"itemByLabel\".to_string(),selector:\"li.menu-itemwheregetTextstringEquals\".to_string()"
+    ));
+}
+
+#[test]
+fn test_nullable_find_first_returns_none() {
+    let code = compile_squashed("filter/filtered-list.utam.json");
+
+    assert!(
+        code.contains("pubasyncfnget_first_visible_badge(&self)->UtamResult<Option<BaseElement>>")
+    );
+    assert!(code.contains(
+        "letvalue=wrapped.is_visible().await?;ifvalue{returnOk(Some(wrapped));}}Ok(None)"
+    ));
+}
+
+#[test]
+fn test_compose_passes_filter_arguments_to_getter() {
+    let code = compile_squashed("filter/filtered-list.utam.json");
+
+    assert!(
+        code.contains("letelement=self.get_item_by_label(label).await?;element.click().await?;")
+    );
+}
+
+#[test]
+fn test_unsupported_matcher_is_rejected() {
+    let error = compile_fixture("invalid/unsupported-matcher.utam.json").unwrap_err();
+    assert!(error.to_string().contains("stringMatchesRegex"));
+}
+
+#[test]
+fn test_filter_applying_action_is_rejected() {
+    let error = compile_fixture("invalid/filter-apply-action.utam.json").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid filter on 'rows': apply method 'click' returns no value to match"
+    );
+}
+
+#[test]
+fn test_matcher_of_wrong_type_is_rejected() {
+    let error = compile_fixture("invalid/filter-matcher-type.utam.json").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid filter on 'rows': matcher 'isTrue' cannot test the String returned by 'getText'"
+    );
+}
+
+#[test]
+fn test_filter_arg_named_like_selector_arg_is_rejected() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "app-list" },
+        "elements": [{
+            "name": "rows",
+            "selector": { "css": "table:nth-of-type(%d) tr", "args": [{ "name": "text", "type": "number" }] },
+            "list": true,
+            "filter": {
+                "apply": "getText",
+                "matcher": { "type": "stringContains", "args": [{ "name": "text", "type": "string" }] }
+            }
+        }]
+    }"#;
+
+    let error = compile(json, CodeGenConfig::default()).unwrap_err();
+    assert_eq!(error.to_string(), "Getter of 'rows' would take two parameters named 'text'");
+}
@@ -0,0 +1,67 @@
+//! Tests for elements declared inside a frame
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_frame_getter_returns_frame_element() {
+    let code = compile_squashed("frame/editor-frame.utam.json");
+
+    assert!(code.contains("pubasyncfnget_editor_frame(&self)->UtamResult<FrameElement>"));
+    assert!(code.contains("letwrapped=FrameElement::new(elem);"));
+}
+
+#[test]
+fn test_component_in_frame_enters_frame() {
+    let code = compile_squashed("frame/editor-frame.utam.json");
+
+    assert!(code.contains("pubasyncfnget_editor(&self)->UtamResult<Framed<RecordEditor>>"));
+    assert!(code.contains(
+        "letframe=self.get_editor_frame().await?;letcontext=frame.enter().await?;letvalue:UtamResult<RecordEditor>=async{letelem=context.find(By::Css(\"record-editor\")).await?;letwrapped=RecordEditor::from_element(elem).await?;Ok(wrapped)}.await;"
+    ));
+    assert!(code.contains(
+        "matchvalue{Ok(value)=>Ok(Framed::new(value,context)),Err(error)=>{context.exit().await?;Err(error)}}"
+    ));
+}
+
+#[test]
+fn test_nullable_element_in_frame() {
+    let code = compile_squashed("frame/editor-frame.utam.json");
+
+    assert!(
+        code.contains("asyncfnget_error_banner(&self)->UtamResult<Framed<Option<BaseElement>>>")
+    );
+    assert!(code.contains(
+        "Ok(framed)=>{letpresent=framed.value().is_some();framed.exit().await?;Ok(present.then_some(()))}_=>Ok(None),"
+    ));
+}
+
+#[test]
+fn test_compose_exits_frame_after_action() {
+    let code = compile_squashed("frame/editor-frame.utam.json");
+
+    assert!(code.contains(
+        "letframed=self.get_save_button().await?;letvalue:UtamResult<_>=async{letelement=framed.value();element.click().await?;Ok(())}.await;letexited=framed.exit().await;value?;exited?;Ok(())"
+    ));
+    assert!(code.contains(
+        "letframed=self.get_editor().await?;letvalue:UtamResult<_>=async{letresult=framed.value().get_record_name().await?;Ok(result)}.await;letexited=framed.exit().await;letresult=value?;exited?;Ok(result)"
+    ));
+}
+
+#[test]
+fn test_compose_exits_frame_before_propagating_errors() {
+    let code = compile_squashed("frame/editor-frame.utam.json");
+
+    // No `?` between entering the frame and exiting it returns early
+    let save = code.split("pubasyncfnsave(&self)").nth(1).expect("save method");
+    let body = &save[..save.find("framed.exit().await;").expect("frame exit")];
+    assert_eq!(body.matches("await?").count(), 2);
+    assert!(body.contains("async{"));
+}
+
+#[test]
+fn test_nested_frames_are_rejected() {
+    let error = compile_fixture("invalid/nested-frames.utam.json").unwrap_err();
+    assert!(error.to_string().contains("nested in frame 'outerFrame'"));
+}
@@ -0,0 +1,42 @@
+//! Tests for `interface` page objects and page objects that implement them
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_interface_generates_trait() {
+    let code = compile_squashed("interface/navigation.utam.json");
+
+    assert!(code.contains("#[async_trait]pubtraitNavigation:PageObject{"));
+    assert!(code.contains("asyncfnget_menu_button(&self)->UtamResult<ClickableElement>;"));
+    assert!(code.contains("asyncfnopen_item(&self,label:&str)->UtamResult<()>;"));
+}
+
+#[test]
+fn test_interface_has_no_struct() {
+    let code = compile_squashed("interface/navigation.utam.json");
+
+    assert!(!code.contains("pubstruct"));
+    assert!(!code.contains("implRootPageObject"));
+}
+
+#[test]
+fn test_implementation_delegates_to_inherent_methods() {
+    let code = compile_squashed("interface/desktop-navigation.utam.json");
+
+    assert!(code.contains("#[async_trait]implNavigationforDesktopNavigation{"));
+    assert!(code.contains(
+        "asyncfnget_menu_button(&self)->UtamResult<ClickableElement>{DesktopNavigation::get_menu_button(self).await}"
+    ));
+    assert!(code.contains(
+        "asyncfnopen_item(&self,label:&str)->UtamResult<()>{DesktopNavigation::open_item(self,label).await}"
+    ));
+}
+
+#[test]
+fn test_implementation_skips_private_elements() {
+    let code = compile_squashed("interface/desktop-navigation.utam.json");
+
+    assert!(!code.contains("DesktopNavigation::get_item("));
+}
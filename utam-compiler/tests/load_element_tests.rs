@@ -0,0 +1,57 @@
+//! Tests for elements marked `load`, checked when a root page object loads
+
+mod common;
+
+use common::*;
+use utam_compiler::CompilerError;
+
+#[test]
+fn test_from_element_loads_elements() {
+    let code = compile_squashed("load/eager-elements.utam.json");
+
+    assert!(code.contains(
+        "asyncfnfrom_element(element:WebElement)->UtamResult<Self>{letpage=Self{root:element};page.load_elements().await?;Ok(page)}"
+    ));
+    assert!(code.contains("asyncfnload_elements(&self)->UtamResult<()>{"));
+}
+
+#[test]
+fn test_missing_element_names_page_object() {
+    let code = compile_squashed("load/eager-elements.utam.json");
+
+    assert!(code.contains(
+        "ifself.get_header().await.is_err(){returnErr(UtamError::ElementNotFound{name:\"EagerElements.header\".to_string(),selector:\"h2.title\".to_string()"
+    ));
+}
+
+#[test]
+fn test_empty_list_and_missing_nullable_fail_to_load() {
+    let code = compile_squashed("load/eager-elements.utam.json");
+
+    assert!(code.contains("if!self.get_fields().await.is_ok_and(|elements|!elements.is_empty()){"));
+    assert!(code.contains("if!self.get_banner().await.is_ok_and(|element|element.is_some()){"));
+}
+
+#[test]
+fn test_elements_not_marked_load_are_not_checked() {
+    let code = compile_squashed("load/eager-elements.utam.json");
+
+    assert!(!code.contains("self.get_save_button().await.is_err()"));
+    assert!(!compile_squashed("basic/simple-element.utam.json").contains("load_elements"));
+}
+
+#[test]
+fn test_parameterized_load_is_rejected() {
+    let error = compile_fixture("invalid/parameterized-load.utam.json").unwrap_err();
+    assert!(error.to_string().contains("Invalid load for 'row': its getter takes arguments"));
+}
+
+#[test]
+fn test_load_in_non_root_page_object_is_rejected() {
+    let error = compile_fixture("invalid/non-root-load.utam.json").unwrap_err();
+    assert!(matches!(
+        error,
+        CompilerError::InvalidLoad { ref element, ref message }
+            if element == "title" && message.contains("not a root")
+    ));
+}
@@ -0,0 +1,72 @@
+//! Tests for compose statements asserting on their value with a `matcher`
+
+mod common;
+
+use common::*;
+use utam_compiler::{compile, CodeGenConfig};
+
+#[test]
+fn test_string_matcher_asserts_value() {
+    let code = compile_squashed("matcher/assertions.utam.json");
+
+    assert!(code.contains("letvalue=self.get_name().await?.get_text().await?;"));
+    assert!(code.contains(
+        "if!(value.to_lowercase()==expected.to_lowercase()){returnErr(UtamError::AssertionFailed{expected:format!(\"{:?}ignoringcase\",expected),actual:format!(\"{:?}\",value),});}"
+    ));
+}
+
+#[test]
+fn test_starts_with_matcher() {
+    let code = compile_squashed("matcher/assertions.utam.json");
+
+    assert!(code.contains("if!(value.starts_with(prefix))"));
+    assert!(code.contains("expected:format!(\"astringstartingwith{:?}\",prefix)"));
+}
+
+#[test]
+fn test_boolean_matcher_guards_following_statements() {
+    let code = compile_squashed("matcher/assertions.utam.json");
+
+    assert!(code.contains(
+        "letvalue=self.get_edit_button().await?.is_enabled().await?;if!(value){returnErr(UtamError::AssertionFailed{expected:\"true\".to_string(),"
+    ));
+    assert!(code.contains("letelement=self.get_edit_button().await?;element.click().await?;"));
+}
+
+#[test]
+fn test_not_null_matcher() {
+    let code = compile_squashed("matcher/assertions.utam.json");
+
+    assert!(code.contains("letvalue=self.get_avatar().await?.get_attribute(\"src\").await?;"));
+    assert!(code.contains("if!(value.is_some()){returnErr(UtamError::AssertionFailed{expected:\"avalue\".to_string(),"));
+}
+
+#[test]
+fn test_unsupported_assertion_is_rejected() {
+    let error = compile_fixture("invalid/unsupported-assertion.utam.json").unwrap_err();
+    assert!(error.to_string().contains("unsupported matcher 'stringMatchesRegex'"));
+}
+
+#[test]
+fn test_matcher_of_wrong_type_is_rejected() {
+    let error = compile_fixture("invalid/assertion-type.utam.json").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid statement: matcher 'isTrue' in method 'assertNameSet' cannot test a value of type String"
+    );
+}
+
+#[test]
+fn test_string_matcher_on_boolean_is_rejected() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "profile-card" },
+        "elements": [{ "name": "editButton", "selector": { "css": ".edit" } }],
+        "methods": [{ "name": "assertEnabled", "compose": [
+            { "element": "editButton", "apply": "isEnabled", "matcher": { "type": "stringEquals", "args": ["true"] } }
+        ]}]
+    }"#;
+
+    let error = compile(json, CodeGenConfig::default()).unwrap_err();
+    assert!(error.to_string().contains("cannot test a value of type bool"));
+}
@@ -0,0 +1,67 @@
+//! Tests for getters of elements nested inside other elements
+
+mod common;
+
+use common::*;
+
+/// Body of the generated method `name`, up to its closing brace
+fn method_body<'a>(code: &'a str, name: &str) -> &'a str {
+    let start = code
+        .find(&format!("fn {}(", name))
+        .unwrap_or_else(|| panic!("method {} not generated:\n{}", name, code));
+    let end = code[start..].find("\n    }\n").map(|i| start + i).unwrap_or(code.len());
+    &code[start..end]
+}
+
+/// Statements in a method body, one per line, without indentation
+fn statements(body: &str) -> Vec<&str> {
+    body.lines().skip(1).map(str::trim).collect()
+}
+
+#[test]
+fn test_nested_element_searches_within_parent() {
+    let code = compile_fixture("shadow-dom/nested-elements.utam.json").expect("Failed to compile");
+
+    assert_eq!(
+        statements(method_body(&code, "get_tree_view"))[..3],
+        [
+            "let shadow = self.root.get_shadow_root().await?;",
+            "let parent = shadow.find(By::Css(\".container\")).await?;",
+            "let elem = parent.find(By::Css(\"lightning-tree\")).await?;",
+        ]
+    );
+}
+
+#[test]
+fn test_nested_shadow_element_enters_parent_shadow_root() {
+    let code = compile_fixture("shadow-dom/nested-elements.utam.json").expect("Failed to compile");
+
+    assert_eq!(
+        statements(method_body(&code, "get_refresh_button"))[..4],
+        [
+            "let shadow = self.root.get_shadow_root().await?;",
+            "let parent = shadow.find(By::Css(\"explorer-toolbar\")).await?;",
+            "let shadow = parent.get_shadow_root().await?;",
+            "let elem = shadow.find(By::Css(\"button.refresh\")).await?;",
+        ]
+    );
+}
+
+#[test]
+fn test_parent_getters_are_still_generated() {
+    let code = compile_fixture("shadow-dom/nested-elements.utam.json").expect("Failed to compile");
+
+    assert!(code.contains("async fn get_tree_container(&self)"));
+    assert!(code.contains("async fn get_toolbar(&self)"));
+    assert!(code.contains("pub async fn get_tree_view(&self)"));
+}
+
+#[test]
+fn test_duplicate_names_across_scopes_are_rejected() {
+    let error = compile_fixture("invalid/duplicate-nested-names.utam.json").unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Elements 'submit' and 'submit' are both generated as 'get_submit'"
+    );
+}
@@ -0,0 +1,52 @@
+//! Tests for getters and compose statements of `nullable` elements
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_nullable_element_returns_option() {
+    let code = compile_squashed("basic/nullable-element.utam.json");
+
+    assert!(
+        code.contains("pubasyncfnget_close_button(&self)->UtamResult<Option<ClickableElement>>")
+    );
+    assert!(code.contains("else{returnOk(None);};"));
+    assert!(code.contains("Ok(Some(wrapped))"));
+}
+
+#[test]
+fn test_nullable_list_returns_empty_list_when_parent_missing() {
+    let code = compile_squashed("basic/nullable-element.utam.json");
+
+    assert!(code.contains("pubasyncfnget_errors(&self)->UtamResult<ElementList<BaseElement>>"));
+    assert!(code.contains(
+        "letSome(parent)=root.find_all(By::Css(\".details\")).await?.into_iter().next()else{returnOk(Vec::new());};"
+    ));
+}
+
+#[test]
+fn test_wait_for_nullable_element_waits_for_some() {
+    let code = compile_squashed("basic/nullable-element.utam.json");
+
+    assert!(
+        code.contains("matchself.get_close_button().await{Ok(Some(_))=>Ok(Some(())),_=>Ok(None),}")
+    );
+}
+
+#[test]
+fn test_compose_skips_missing_nullable_element() {
+    let code = compile_squashed("basic/nullable-element.utam.json");
+
+    assert!(
+        code.contains("ifletSome(element)=self.get_close_button().await?{element.click().await?;}")
+    );
+}
+
+#[test]
+fn test_non_nullable_element_is_unchanged() {
+    let code = compile_fixture("basic/clickable-button.utam.json").expect("Failed to compile");
+
+    assert!(!code.contains("Option<"));
+    assert!(!code.contains("find_all"));
+}
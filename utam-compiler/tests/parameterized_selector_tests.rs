@@ -1,5 +1,8 @@
 //! Integration tests for parameterized selector parsing
 
+mod common;
+
+use common::*;
 use utam_compiler::ast::*;
 use utam_compiler::codegen::generate_selector_code;
 
@@ -75,10 +78,7 @@ fn test_parse_multiple_parameters_selector() {
         assert_eq!(element.name, "dynamicInput");
 
         if let Some(selector) = &element.selector {
-            assert_eq!(
-                selector.css,
-                Some("input[data-type='%s'][data-index='%d']".to_string())
-            );
+            assert_eq!(selector.css, Some("input[data-type='%s'][data-index='%d']".to_string()));
             assert_eq!(selector.args.len(), 2);
             assert_eq!(selector.args[0].name, "inputType");
             assert_eq!(selector.args[0].arg_type, "string");
@@ -109,7 +109,7 @@ fn test_codegen_for_parameterized_selector() {
             // Verify the generated code contains the expected elements
             assert!(code_str.contains("thirtyfour :: By :: Css"));
             assert!(code_str.contains("format !"));
-            assert!(code_str.contains("buttonId"));
+            assert!(code_str.contains("button_id"));
             assert!(code_str.contains("{}"));
         }
     }
@@ -124,14 +124,8 @@ fn test_validate_parameter_mismatch() {
         classchain: None,
         uiautomator: None,
         args: vec![
-            SelectorArgAst {
-                name: "id1".to_string(),
-                arg_type: "string".to_string(),
-            },
-            SelectorArgAst {
-                name: "id2".to_string(),
-                arg_type: "string".to_string(),
-            },
+            SelectorArgAst { name: "id1".to_string(), arg_type: "string".to_string() },
+            SelectorArgAst { name: "id2".to_string(), arg_type: "string".to_string() },
         ],
         return_all: false,
     };
@@ -139,3 +133,50 @@ fn test_validate_parameter_mismatch() {
     let result = selector.validate();
     assert!(result.is_err());
 }
+
+#[test]
+fn test_getter_takes_selector_parameters() {
+    let code = compile_json_squashed(
+        include_str!("../../testdata/parameterized/string-parameter.utam.json"),
+        "DataTable",
+    );
+
+    assert!(code.contains("asyncfnget_dynamic_button(&self,button_id:&str)"));
+    assert!(code.contains("format!(\"button[data-id='{}']\",button_id)"));
+}
+
+#[test]
+fn test_nested_getter_takes_parent_parameters_first() {
+    let code = compile_json_squashed(
+        include_str!("../../testdata/parameterized/compose-parameters.utam.json"),
+        "DataTable",
+    );
+
+    assert!(code.contains(
+        "pubasyncfnget_cell(&self,row_key:&str,column:i64)->UtamResult<EditableElement>"
+    ));
+    assert!(code.contains(
+        "letparent=self.root.find(thirtyfour::By::Css(&format!(\"tr[data-row-key='{}']\",row_key"
+    ));
+    assert!(code
+        .contains("letelem=parent.find(thirtyfour::By::Css(&format!(\"td:nth-child({})\",column"));
+}
+
+#[test]
+fn test_compose_passes_selector_args_to_getter() {
+    let code = compile_json_squashed(
+        include_str!("../../testdata/parameterized/compose-parameters.utam.json"),
+        "DataTable",
+    );
+
+    assert!(code.contains("pubasyncfnedit_cell(&self,row_key:&str,text:&str)"));
+    assert!(code.contains("self.get_cell(row_key,2i64).await?;"));
+    assert!(code.contains(".clear_and_type(text).await?;"));
+}
+
+#[test]
+fn test_nested_arg_named_like_parent_arg_is_rejected() {
+    let error = compile_fixture("invalid/duplicate-selector-args.utam.json").unwrap_err();
+
+    assert_eq!(error.to_string(), "Getter of 'cell' would take two parameters named 'index'");
+}
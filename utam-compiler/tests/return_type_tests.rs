@@ -0,0 +1,57 @@
+//! Tests for method return types inferred from the last compose statement
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_action_return_types() {
+    let code = compile_squashed("return-types/inferred-returns.utam.json");
+
+    assert!(code.contains("pubasyncfnget_title_text(&self)->UtamResult<String>"));
+    assert!(code.contains("letresult=self.get_title().await?.get_text().await?;Ok(result)"));
+    assert!(code.contains("pubasyncfnis_title_visible(&self)->UtamResult<bool>"));
+    assert!(code.contains("pubasyncfnget_link_target(&self)->UtamResult<Option<String>>"));
+    assert!(code.contains("letresult=self.get_link().await?.get_attribute(\"href\").await?;"));
+}
+
+#[test]
+fn test_return_all_collects_list_values() {
+    let code = compile_squashed("return-types/inferred-returns.utam.json");
+
+    assert!(code.contains("pubasyncfnget_tag_labels(&self)->UtamResult<Vec<String>>"));
+    assert!(code.contains(
+        "letmutresult=Vec::new();forelementinself.get_tags().await?{result.push(element.get_text().await?);}Ok(result)"
+    ));
+}
+
+#[test]
+fn test_element_return_types() {
+    let code = compile_squashed("return-types/inferred-returns.utam.json");
+
+    assert!(code.contains("pubasyncfnfind_badge(&self)->UtamResult<Option<BaseElement>>"));
+    assert!(code.contains("letresult=self.get_badge().await?;Ok(result)"));
+    assert!(code.contains("pubasyncfnfind_rating(&self)->UtamResult<Rating>"));
+}
+
+#[test]
+fn test_void_action_returns_unit() {
+    let code = compile_squashed("return-types/inferred-returns.utam.json");
+
+    assert!(code.contains("pubasyncfnfocus_title(&self)->UtamResult<()>"));
+}
+
+#[test]
+fn test_declared_return_type_matching_inferred() {
+    let code = compile_squashed("return-types/inferred-returns.utam.json");
+
+    assert!(code.contains("pubasyncfnget_title_label(&self)->UtamResult<String>"));
+}
+
+#[test]
+fn test_declared_return_type_mismatch_is_rejected() {
+    let error = compile_fixture("invalid/return-type-mismatch.utam.json").unwrap_err();
+    assert!(error.to_string().contains(
+        "Method 'getTitleText' declares return type bool, but its last statement returns String"
+    ));
+}
@@ -0,0 +1,44 @@
+//! Tests for exposing the root element with `exposeRootElement`
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_root_getter_uses_root_type() {
+    let code = compile_squashed("basic/editable-input.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_root(&self)->UtamResult<EditableElement>{Ok(EditableElement::new(self.root.clone()))}"
+    ));
+}
+
+#[test]
+fn test_root_getter_without_type_is_base_element() {
+    let code = compile_json_squashed(
+        r#"{ "root": true, "selector": { "css": "my-host" }, "exposeRootElement": true }"#,
+        "Host",
+    );
+
+    assert!(code.contains("pubasyncfnget_root(&self)->UtamResult<BaseElement>"));
+}
+
+#[test]
+fn test_root_not_exposed_by_default() {
+    let code = compile_json_squashed(
+        r#"{ "root": true, "selector": { "css": "my-host" }, "type": ["draggable"] }"#,
+        "Host",
+    );
+
+    assert!(!code.contains("fnget_root"));
+}
+
+#[test]
+fn test_interface_declares_root_getter() {
+    let code = compile_json_squashed(
+        r#"{ "interface": true, "root": true, "type": ["clickable"], "exposeRootElement": true }"#,
+        "Host",
+    );
+
+    assert!(code.contains("asyncfnget_root(&self)->UtamResult<ClickableElement>;"));
+}
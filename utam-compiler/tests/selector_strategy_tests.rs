@@ -0,0 +1,65 @@
+//! Tests for mobile selector strategies and platform checks
+
+mod common;
+
+use common::*;
+use utam_compiler::{CompilerError, SelectorType};
+
+#[test]
+fn test_mobile_strategies_use_locate() {
+    let code = compile_squashed("mobile/native-selectors.utam.json");
+
+    assert!(
+        code.contains("self.root.locate(&Selector::AccessibilityId(\"Back\".to_string())).await?")
+    );
+    assert!(code.contains("self.root.locate(&Selector::IosClassChain("));
+    assert!(code.contains("root.locate_all(&Selector::AndroidUiAutomator("));
+    assert!(!code.contains("By::Css"));
+}
+
+#[test]
+fn test_parameterized_mobile_selector() {
+    let code = compile_squashed("mobile/native-selectors.utam.json");
+
+    assert!(code.contains("fnget_tab_with_label(&self,label:&str)"));
+    assert!(code.contains(
+        "Selector::AndroidUiAutomator(format!(\"newUiSelector().description(\\\"{}\\\")\",label))"
+    ));
+}
+
+#[test]
+fn test_native_root_selector() {
+    let code = compile_squashed("mobile/native-selectors.utam.json");
+
+    assert!(code.contains("constROOT_SELECTOR:&'staticstr=\"NavigationBar\";"));
+    assert!(code.contains(
+        "driver.locate(&Selector::AccessibilityId(\"NavigationBar\".to_string())).await?"
+    ));
+}
+
+#[test]
+fn test_mobile_selector_on_web_is_rejected() {
+    let error = compile_fixture("invalid/unsupported-selector.utam.json").unwrap_err();
+
+    match error {
+        CompilerError::UnsupportedSelector { element, strategy, platform } => {
+            assert_eq!(element, "submit");
+            assert_eq!(strategy, "accessid");
+            assert_eq!(platform, "web");
+        }
+        other => panic!("Expected UnsupportedSelector, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_supported_on_platform() {
+    let css = SelectorType::Css(".a".to_string());
+    let accessid = SelectorType::AccessibilityId("a".to_string());
+
+    assert!(css.supported_on(None));
+    assert!(css.supported_on(Some("web")));
+    assert!(!css.supported_on(Some("native")));
+    assert!(!accessid.supported_on(None));
+    assert!(accessid.supported_on(Some("native")));
+    assert!(SelectorType::Unknown.supported_on(Some("native")));
+}
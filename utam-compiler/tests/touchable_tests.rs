@@ -0,0 +1,32 @@
+//! Tests for elements with the `touchable` action type
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_touchable_element_getter() {
+    let code = compile_squashed("mobile/touch-list.utam.json");
+
+    assert!(code.contains("pubasyncfnget_first_record(&self)->UtamResult<TouchableElement>"));
+    assert!(code.contains("letwrapped=TouchableElement::new(elem);"));
+}
+
+#[test]
+fn test_touchable_root() {
+    let code = compile_squashed("mobile/touch-list.utam.json");
+
+    assert!(code.contains(
+        "pubasyncfnget_root(&self)->UtamResult<TouchableElement>{Ok(TouchableElement::new(self.root.clone()))}"
+    ));
+    assert!(code.contains(
+        "letelement=TouchableElement::new(self.root.clone());element.flick(0i64,400i64).await?;"
+    ));
+}
+
+#[test]
+fn test_touch_action_on_element() {
+    let code = compile_squashed("mobile/touch-list.utam.json");
+
+    assert!(code.contains("letelement=self.get_first_record().await?;element.tap().await?;"));
+}
@@ -0,0 +1,75 @@
+//! Tests for `waitFor` compose statements with a predicate
+
+mod common;
+
+use std::time::Duration;
+
+use common::*;
+use utam_compiler::{compile, CodeGenConfig};
+
+#[test]
+fn test_predicate_polls_until_true() {
+    let code = compile_squashed("wait/wait-for-predicate.utam.json");
+
+    assert!(code.contains("pubasyncfnsave_and_wait(&self,timeout:i64)->UtamResult<bool>"));
+    assert!(code.contains("lettimeout=std::time::Duration::from_millis(timeoutasu64);"));
+    assert!(code.contains(
+        "wait_for(||async{letvalue=self.get_status().await?.is_visible().await?;Ok(value.then_some(true))},&config,\"waitForpredicatetohold\""
+    ));
+}
+
+#[test]
+fn test_predicate_on_nullable_element_waits_for_presence() {
+    let code = compile_squashed("wait/wait-for-predicate.utam.json");
+
+    assert!(code.contains("lettimeout=WaitConfig::default().timeout;"));
+    assert!(code.contains("wait_for(||async{letvalue=self.get_toast().await?;Ok(value)}"));
+}
+
+#[test]
+fn test_predicate_with_chained_statements() {
+    let code = compile_squashed("wait/wait-for-predicate.utam.json");
+
+    assert!(code.contains("pubasyncfnwait_for_status_text(&self)->UtamResult<String>"));
+    assert!(code.contains("lettimeout=std::time::Duration::from_millis(5000i64asu64);"));
+    assert!(code.contains(
+        "wait_for(||async{letprevious=self.get_status().await?;letvalue=previous.get_text().await?;Ok(Some(value))}"
+    ));
+}
+
+#[test]
+fn test_predicate_timeout_from_config() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "save-form" },
+        "elements": [{ "name": "status", "selector": { "css": ".status" } }],
+        "methods": [{ "name": "waitForStatus", "compose": [
+            { "apply": "waitFor", "predicate": [{ "element": "status", "apply": "isVisible" }] }
+        ]}]
+    }"#;
+    let config = CodeGenConfig {
+        module_name: Some("SaveForm".to_string()),
+        wait_timeout: Some(Duration::from_secs(3)),
+        ..Default::default()
+    };
+
+    let code: String = squash(&compile(json, config).expect("Failed to compile"));
+    assert!(code.contains("lettimeout=std::time::Duration::from_millis(3000u64);"));
+}
+
+#[test]
+fn test_unreturned_wait_result_is_not_bound() {
+    let json = r#"{
+        "root": true,
+        "selector": { "css": "save-form" },
+        "elements": [{ "name": "status", "type": ["clickable"], "selector": { "css": ".status" } }],
+        "methods": [{ "name": "waitAndClick", "compose": [
+            { "apply": "waitFor", "predicate": [{ "element": "status", "apply": "isVisible" }] },
+            { "element": "status", "apply": "click" }
+        ]}]
+    }"#;
+
+    let code = compile_json_squashed(json, "SaveForm");
+    assert!(!code.contains("letresult="));
+    assert!(code.contains("\"waitForpredicatetohold\").await?};letelement=self.get_status()"));
+}
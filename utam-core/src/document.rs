@@ -0,0 +1,77 @@
+//! Document access for UTAM
+//!
+//! This module provides the `document` pseudo-element that compose
+//! statements use to act on the page itself: reading its URL, waiting for it
+//! to finish loading, checking for an element anywhere on it, or loading a
+//! page object inside one of its frames.
+
+use thirtyfour::prelude::*;
+
+use crate::elements::FrameElement;
+use crate::error::UtamResult;
+use crate::selector::{Locate, Selector};
+use crate::traits::RootPageObject;
+use crate::wait::{wait_for, WaitConfig};
+
+/// The current document of a WebDriver session
+#[derive(Debug, Clone)]
+pub struct Document {
+    driver: WebDriver,
+}
+
+impl Document {
+    /// Create a new Document for the driver's current page
+    pub fn new(driver: &WebDriver) -> Self {
+        Self { driver: driver.clone() }
+    }
+
+    /// Create a Document for the page an element is on
+    ///
+    /// Page object methods only hold their root element, so they reach the
+    /// document through its session.
+    pub fn of(element: &WebElement) -> Self {
+        Self { driver: WebDriver { handle: element.handle.clone() } }
+    }
+
+    /// Get the URL of the current page
+    pub async fn get_url(&self) -> UtamResult<String> {
+        Ok(self.driver.current_url().await?.to_string())
+    }
+
+    /// Wait until the document has finished loading
+    ///
+    /// The document is ready once its `readyState` is `complete`.
+    ///
+    /// # Errors
+    ///
+    /// * `UtamError::Timeout` - When the document is not ready within
+    ///   `config.timeout`
+    pub async fn wait_for_document_ready(&self, config: &WaitConfig) -> UtamResult<()> {
+        wait_for(
+            || async {
+                let state = self.driver.execute("return document.readyState", vec![]).await?;
+                Ok((state.json().as_str() == Some("complete")).then_some(()))
+            },
+            config,
+            "document to be ready",
+        )
+        .await
+    }
+
+    /// Check if an element matching `selector` is anywhere on the page
+    pub async fn contains_element(&self, selector: &Selector) -> UtamResult<bool> {
+        Ok(!self.driver.locate_all(selector).await?.is_empty())
+    }
+
+    /// Switch to a frame and load a root page object inside it
+    ///
+    /// The driver stays in the frame, so later lookups on the page object
+    /// find its elements.
+    pub async fn enter_frame_and_load<T: RootPageObject>(
+        &self,
+        frame: &FrameElement,
+    ) -> UtamResult<T> {
+        frame.inner().clone().enter_frame().await?;
+        T::load(&self.driver).await
+    }
+}
@@ -52,14 +52,13 @@ impl<T: PageObject> Container<T> {
         self
     }
 
-    async fn find_element(&self) -> UtamResult<WebElement> {
-        let selector = self
-            .selector
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(|| By::Css(DEFAULT_CONTAINER_SELECTOR.to_string()));
+    /// Selector of the contained element
+    fn content_selector(&self) -> By {
+        self.selector.clone().unwrap_or_else(|| By::Css(DEFAULT_CONTAINER_SELECTOR.to_string()))
+    }
 
-        self.root.find(selector.clone()).await.map_err(|e| UtamError::ElementNotFound {
+    async fn find_element(&self) -> UtamResult<WebElement> {
+        self.root.find(self.content_selector()).await.map_err(|e| UtamError::ElementNotFound {
             name: format!("container content ({})", e),
             selector: self
                 .selector
@@ -69,6 +68,11 @@ impl<T: PageObject> Container<T> {
         })
     }
 
+    /// Check whether the container currently has content to load
+    pub async fn is_present(&self) -> UtamResult<bool> {
+        Ok(!self.root.find_all(self.content_selector()).await?.is_empty())
+    }
+
     /// Load the contained page object
     pub async fn load(&self) -> UtamResult<T>
     where
@@ -0,0 +1,152 @@
+//! Element - wrapper exposing the actions of its declared capabilities
+//!
+//! An element declared with several action types, such as
+//! `["clickable", "editable"]`, is an `Element<(Click, Edit)>`: it
+//! implements exactly the union of the matching action traits, and derefs
+//! to [`BaseElement`] for queries like `get_text` and `wait_for_visible`.
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use thirtyfour::WebElement;
+
+use crate::elements::BaseElement;
+use crate::traits::{Actionable, Clickable, Draggable, Editable, Touchable};
+
+/// Capability marker for [`Clickable`] elements
+#[derive(Debug, Clone, Copy)]
+pub enum Click {}
+
+/// Capability marker for [`Editable`] elements
+#[derive(Debug, Clone, Copy)]
+pub enum Edit {}
+
+/// Capability marker for [`Draggable`] elements
+#[derive(Debug, Clone, Copy)]
+pub enum Drag {}
+
+/// Capability marker for [`Touchable`] elements
+#[derive(Debug, Clone, Copy)]
+pub enum Touch {}
+
+/// Capabilities of an [`Element`]
+///
+/// Implemented by `()`, by each capability marker, and by tuples of
+/// markers in the order `Click`, `Edit`, `Drag`, `Touch`.
+pub trait Capabilities: Send + Sync + 'static {}
+
+/// Capabilities that include [`Click`]
+pub trait CanClick: Capabilities {}
+
+/// Capabilities that include [`Edit`]
+pub trait CanEdit: Capabilities {}
+
+/// Capabilities that include [`Drag`]
+pub trait CanDrag: Capabilities {}
+
+/// Capabilities that include [`Touch`]
+pub trait CanTouch: Capabilities {}
+
+macro_rules! capabilities {
+    ($($set:ty => [$($can:ident),*];)*) => {
+        $(
+            impl Capabilities for $set {}
+            $(impl $can for $set {})*
+        )*
+    };
+}
+
+capabilities! {
+    () => [];
+    Click => [CanClick];
+    Edit => [CanEdit];
+    Drag => [CanDrag];
+    Touch => [CanTouch];
+    (Click, Edit) => [CanClick, CanEdit];
+    (Click, Drag) => [CanClick, CanDrag];
+    (Click, Touch) => [CanClick, CanTouch];
+    (Edit, Drag) => [CanEdit, CanDrag];
+    (Edit, Touch) => [CanEdit, CanTouch];
+    (Drag, Touch) => [CanDrag, CanTouch];
+    (Click, Edit, Drag) => [CanClick, CanEdit, CanDrag];
+    (Click, Edit, Touch) => [CanClick, CanEdit, CanTouch];
+    (Click, Drag, Touch) => [CanClick, CanDrag, CanTouch];
+    (Edit, Drag, Touch) => [CanEdit, CanDrag, CanTouch];
+    (Click, Edit, Drag, Touch) => [CanClick, CanEdit, CanDrag, CanTouch];
+}
+
+/// Element wrapper with the actions of its capabilities `C`
+#[derive(Debug, Clone)]
+pub struct Element<C: Capabilities> {
+    base: BaseElement,
+    _capabilities: PhantomData<C>,
+}
+
+/// Element wrapper for clickable elements
+pub type ClickableElement = Element<Click>;
+
+/// Element wrapper for editable elements
+pub type EditableElement = Element<Edit>;
+
+/// Element wrapper for draggable elements
+pub type DraggableElement = Element<Drag>;
+
+/// Element wrapper for touchable elements
+pub type TouchableElement = Element<Touch>;
+
+impl<C: Capabilities> Element<C> {
+    /// Create a new Element from a WebElement
+    pub fn new(element: WebElement) -> Self {
+        Self { base: BaseElement::new(element), _capabilities: PhantomData }
+    }
+
+    /// Get the underlying WebElement
+    pub fn inner(&self) -> &WebElement {
+        self.base.inner()
+    }
+}
+
+impl<C: Capabilities> Deref for Element<C> {
+    type Target = BaseElement;
+
+    fn deref(&self) -> &BaseElement {
+        &self.base
+    }
+}
+
+#[async_trait]
+impl<C: Capabilities> Actionable for Element<C> {
+    fn inner(&self) -> &WebElement {
+        self.base.inner()
+    }
+}
+
+#[async_trait]
+impl<C: CanClick> Clickable for Element<C> {}
+
+#[async_trait]
+impl<C: CanEdit> Editable for Element<C> {}
+
+#[async_trait]
+impl<C: CanDrag> Draggable for Element<C> {}
+
+#[async_trait]
+impl<C: CanTouch> Touchable for Element<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_clickable<T: Clickable>() {}
+    fn assert_editable<T: Editable>() {}
+    fn assert_touchable<T: Touchable>() {}
+
+    #[test]
+    fn test_element_has_union_of_capabilities() {
+        assert_clickable::<Element<(Click, Edit)>>();
+        assert_editable::<Element<(Click, Edit)>>();
+        assert_clickable::<Element<(Click, Drag, Touch)>>();
+        assert_touchable::<Element<(Click, Drag, Touch)>>();
+    }
+}
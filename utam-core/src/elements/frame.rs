@@ -3,6 +3,8 @@
 //! This module provides support for switching into iframe contexts and back.
 //! Uses RAII pattern for automatic context cleanup.
 
+use std::ops::Deref;
+
 use thirtyfour::prelude::*;
 
 use crate::error::UtamResult;
@@ -110,6 +112,11 @@ impl FrameContext {
         Ok(self.driver.find(by).await?)
     }
 
+    /// Find all elements matching the selector within frame
+    pub async fn find_all(&self, by: By) -> UtamResult<Vec<WebElement>> {
+        Ok(self.driver.find_all(by).await?)
+    }
+
     /// Explicitly exit frame (or let it auto-exit on drop)
     ///
     /// This method switches back to the parent frame context.
@@ -132,6 +139,50 @@ impl FrameContext {
     }
 }
 
+/// A value found inside a frame, together with the frame's context
+///
+/// Generated getters of elements declared inside a frame enter the frame
+/// and return the element wrapped in `Framed`, so it can be used while the
+/// driver is still switched into the frame. The value is reachable through
+/// `Deref`; call `exit()` when done to switch back to the parent frame.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let form = page.get_editor_form().await?;
+/// form.save().await?;
+/// form.exit().await?;
+/// ```
+pub struct Framed<T> {
+    value: T,
+    context: FrameContext,
+}
+
+impl<T> Framed<T> {
+    /// Wrap a value found in the frame that `context` switched into
+    pub fn new(value: T, context: FrameContext) -> Self {
+        Self { value, context }
+    }
+
+    /// Get the wrapped value
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Switch back to the parent frame, discarding the value
+    pub async fn exit(self) -> UtamResult<()> {
+        self.context.exit().await
+    }
+}
+
+impl<T> Deref for Framed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
 impl Drop for FrameContext {
     fn drop(&mut self) {
         // Only run drop cleanup if exit() was not called
@@ -189,6 +240,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_framed_api_exists() {
+        // Verify API exists at compile time
+        fn _check_api_exists() {
+            #[allow(unreachable_code)]
+            #[allow(clippy::diverging_sub_expression)]
+            {
+                let _framed: Framed<FrameElement> = panic!("not meant to run");
+                let _ = _framed.value().inner();
+                let _ = _framed.inner();
+            }
+        }
+    }
+
     #[test]
     fn test_frame_element_debug() {
         // Test Debug trait implementation exists
@@ -0,0 +1,289 @@
+//! Typed collection for list elements
+//!
+//! `ElementList<T>` holds the elements a list getter found, along with the
+//! query that found them. The query is re-run to wait for a number of
+//! elements, or when an element has gone stale.
+
+use std::future::Future;
+use std::ops::Index;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use thirtyfour::WebElement;
+
+use crate::elements::{BaseElement, Capabilities, Element, FrameElement};
+use crate::error::{UtamError, UtamResult};
+use crate::traits::PageObject;
+use crate::wait::{wait_for, WaitConfig};
+
+type ListFuture<T> = Pin<Box<dyn Future<Output = UtamResult<Vec<T>>> + Send>>;
+
+/// Query finding the current elements of a list
+type ListQuery<T> = Arc<dyn Fn() -> ListFuture<T> + Send + Sync>;
+
+/// Value backed by a single `WebElement`, which can be checked for staleness
+pub trait ListItem: Send + Sync {
+    /// Get the underlying WebElement
+    fn web_element(&self) -> &WebElement;
+}
+
+impl ListItem for BaseElement {
+    fn web_element(&self) -> &WebElement {
+        self.inner()
+    }
+}
+
+impl<C: Capabilities> ListItem for Element<C> {
+    fn web_element(&self) -> &WebElement {
+        self.inner()
+    }
+}
+
+impl ListItem for FrameElement {
+    fn web_element(&self) -> &WebElement {
+        self.inner()
+    }
+}
+
+impl<T: PageObject> ListItem for T {
+    fn web_element(&self) -> &WebElement {
+        self.root()
+    }
+}
+
+/// Elements returned by a list element's getter
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut rows = table.get_rows().await?;
+/// rows.wait_for_count(5, Duration::from_secs(10)).await?;
+/// let first = rows.get(0).await?;
+/// let texts = rows.texts().await?;
+/// ```
+pub struct ElementList<T> {
+    items: Vec<T>,
+    query: Option<ListQuery<T>>,
+}
+
+impl<T> ElementList<T> {
+    /// Create a list of fixed elements, which is never re-queried
+    pub fn new(items: Vec<T>) -> Self {
+        Self { items, query: None }
+    }
+
+    /// Number of elements in the list
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the list has no elements
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate over the elements
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Get the first element matching a predicate
+    pub fn find_first(&self, predicate: impl Fn(&T) -> bool) -> Option<&T> {
+        self.items.iter().find(|item| predicate(item))
+    }
+
+    /// Keep the elements for which an async predicate is true
+    ///
+    /// The filtered list is not re-queried, since its query would find the
+    /// unfiltered elements.
+    pub async fn filter<F, Fut>(self, predicate: F) -> UtamResult<Self>
+    where
+        F: Fn(&T) -> Fut,
+        Fut: Future<Output = UtamResult<bool>>,
+    {
+        let mut items = Vec::new();
+        for item in self.items {
+            if predicate(&item).await? {
+                items.push(item);
+            }
+        }
+        Ok(Self::new(items))
+    }
+
+    /// Take the elements out of the list
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T: Send + 'static> ElementList<T> {
+    /// Run a query for the list's elements, keeping it to re-query later
+    pub async fn query<F, Fut>(query: F) -> UtamResult<Self>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = UtamResult<Vec<T>>> + Send + 'static,
+    {
+        let query: ListQuery<T> = Arc::new(move || Box::pin(query()));
+        let items = query().await?;
+        Ok(Self { items, query: Some(query) })
+    }
+
+    /// Re-run the query, replacing the list's elements
+    ///
+    /// A list of fixed elements is left unchanged.
+    pub async fn refresh(&mut self) -> UtamResult<()> {
+        if let Some(query) = &self.query {
+            self.items = query().await?;
+        }
+        Ok(())
+    }
+
+    /// Wait until the list has exactly `count` elements
+    ///
+    /// The query is re-run every poll; a list of fixed elements is only
+    /// checked once.
+    pub async fn wait_for_count(&mut self, count: usize, timeout: Duration) -> UtamResult<()> {
+        let condition = format!("list to have {} elements", count);
+        let Some(query) = self.query.clone() else {
+            if self.items.len() == count {
+                return Ok(());
+            }
+            return Err(UtamError::Timeout { condition });
+        };
+        self.items = wait_for(
+            || {
+                let query = query.clone();
+                async move {
+                    let items = query().await?;
+                    Ok((items.len() == count).then_some(items))
+                }
+            },
+            &WaitConfig { timeout, ..Default::default() },
+            &condition,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+impl<T: ListItem + 'static> ElementList<T> {
+    /// Get the element at `index`
+    ///
+    /// If that element has gone stale the list is re-queried first, so the
+    /// element returned is the one currently at `index`.
+    pub async fn get(&mut self, index: usize) -> UtamResult<&T> {
+        let stale = match self.items.get(index) {
+            Some(item) => !BaseElement::new(item.web_element().clone()).is_present().await?,
+            None => false,
+        };
+        if stale {
+            self.refresh().await?;
+        }
+        let len = self.items.len();
+        self.items.get(index).ok_or_else(|| UtamError::ElementNotFound {
+            name: format!("list element {}", index),
+            selector: format!("index {} of {} elements", index, len),
+        })
+    }
+
+    /// Get the visible text of every element
+    pub async fn texts(&self) -> UtamResult<Vec<String>> {
+        let mut texts = Vec::new();
+        for item in &self.items {
+            texts.push(item.web_element().text().await?);
+        }
+        Ok(texts)
+    }
+}
+
+impl<T> Index<usize> for ElementList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+}
+
+impl<T> IntoIterator for ElementList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ElementList<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Query finding one more element each time it is run
+    fn counting_query() -> impl Fn() -> ListFuture<usize> {
+        let calls = Arc::new(AtomicUsize::new(0));
+        move || {
+            let calls = calls.clone();
+            Box::pin(async move {
+                let count = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok((0..count).collect())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fixed_list() {
+        let list = ElementList::new(vec![1, 2, 3]);
+
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+        assert_eq!(list[1], 2);
+        assert_eq!(list.find_first(|n| *n > 1), Some(&2));
+        assert_eq!(list.iter().sum::<i32>(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_filter_keeps_matches() {
+        let list = ElementList::new(vec![1, 2, 3, 4]);
+        let even = list.filter(|n| std::future::ready(Ok(n % 2 == 0))).await.unwrap();
+
+        assert_eq!(even.into_vec(), vec![2, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reruns_query() {
+        let mut list = ElementList::query(counting_query()).await.unwrap();
+        assert_eq!(list.len(), 1);
+
+        list.refresh().await.unwrap();
+        assert_eq!(list.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_count_polls_query() {
+        let mut list = ElementList::query(counting_query()).await.unwrap();
+        list.wait_for_count(3, Duration::from_secs(5)).await.unwrap();
+
+        assert_eq!(list.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_count_on_fixed_list() {
+        let mut list = ElementList::new(vec!["a"]);
+
+        assert!(list.wait_for_count(1, Duration::from_millis(10)).await.is_ok());
+        assert!(matches!(
+            list.wait_for_count(2, Duration::from_millis(10)).await,
+            Err(UtamError::Timeout { .. })
+        ));
+    }
+}
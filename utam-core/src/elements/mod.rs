@@ -7,26 +7,30 @@
 //! # Element Types
 //!
 //! - [`BaseElement`] - Core wrapper with attribute queries, state checks, wait utilities
-//! - [`ClickableElement`] - Implements [`Clickable`](crate::traits::Clickable)
-//! - [`EditableElement`] - Implements [`Editable`](crate::traits::Editable)
-//! - [`DraggableElement`] - Implements [`Draggable`](crate::traits::Draggable)
+//! - [`Element`] - Implements the action traits of its capabilities, such as
+//!   `Element<(Click, Edit)>`, and derefs to [`BaseElement`]
+//! - [`ClickableElement`], [`EditableElement`], [`DraggableElement`] and
+//!   [`TouchableElement`] - Elements with a single capability
 //! - [`Container`] - Generic container for dynamic/slot content
 //! - [`FrameElement`] - Wrapper for iframe elements with context switching
 //! - [`FrameContext`] - RAII guard for iframe context management
+//! - [`Framed`] - Value found inside an iframe, with its context
+//! - [`ElementList`] - Elements of a list element, re-queried when stale
 //! - [`ElementRectangle`] - Position and size data
 
 mod base;
-mod clickable;
 mod container;
-mod draggable;
-mod editable;
+mod element;
 mod frame;
+mod list;
 mod rectangle;
 
 pub use base::BaseElement;
-pub use clickable::ClickableElement;
 pub use container::Container;
-pub use draggable::DraggableElement;
-pub use editable::EditableElement;
-pub use frame::{FrameContext, FrameElement};
+pub use element::{
+    CanClick, CanDrag, CanEdit, CanTouch, Capabilities, Click, ClickableElement, Drag,
+    DraggableElement, Edit, EditableElement, Element, Touch, TouchableElement,
+};
+pub use frame::{FrameContext, FrameElement, Framed};
+pub use list::{ElementList, ListItem};
 pub use rectangle::ElementRectangle;
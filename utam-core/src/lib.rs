@@ -5,9 +5,11 @@
 //!
 //! # Module Structure
 //!
-//! - [`elements`] - Element wrappers (BaseElement, ClickableElement, etc.)
-//! - [`traits`] - Async traits (Actionable, Clickable, Editable, Draggable, PageObject)
+//! - [`document`] - The current page's document (Document)
+//! - [`elements`] - Element wrappers (BaseElement, Element, ClickableElement, etc.)
+//! - [`traits`] - Async traits (Actionable, Clickable, Editable, Draggable, Touchable, PageObject)
 //! - [`error`] - Error types (UtamError, UtamResult)
+//! - [`selector`] - Selector strategies for web and mobile (Selector, Locate)
 //! - [`shadow`] - Shadow DOM support (ShadowRoot, traverse_shadow_path)
 //! - [`wait`] - Wait utilities (WaitConfig, wait_for)
 //!
@@ -21,18 +23,24 @@
 //! login.login("user", "pass").await?;
 //! ```
 
+pub mod document;
 pub mod elements;
 pub mod error;
+pub mod selector;
 pub mod shadow;
 pub mod traits;
 pub mod wait;
 
 pub mod prelude {
+    pub use crate::document::Document;
     pub use crate::elements::*;
     pub use crate::error::{UtamError, UtamResult};
+    pub use crate::selector::{Locate, Selector};
     pub use crate::shadow::*;
     pub use crate::traits::*;
     pub use crate::wait::*;
+    // Generated interface traits and their impls are async
+    pub use async_trait::async_trait;
     // Re-export thirtyfour essentials explicitly to avoid Key name collision
     pub use thirtyfour::prelude::{By, WebDriver, WebDriverError, WebElement};
 }
@@ -0,0 +1,131 @@
+//! Selector strategies for UTAM page objects
+//!
+//! UTAM selectors are CSS for web pages and Appium locator strategies for
+//! native mobile apps. thirtyfour's [`By`] only covers the W3C web
+//! strategies, so [`Selector`] sends the mobile strategies to the driver
+//! as raw WebDriver locators instead.
+
+use async_trait::async_trait;
+use thirtyfour::common::command::{Command, Selector as Locator};
+use thirtyfour::prelude::*;
+
+use crate::error::UtamResult;
+
+/// A UTAM selector together with its locator strategy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// CSS selector (`css`)
+    Css(String),
+    /// Accessibility ID (`accessid`), on iOS and Android
+    AccessibilityId(String),
+    /// iOS class chain (`classchain`)
+    IosClassChain(String),
+    /// Android UI Automator expression (`uiautomator`)
+    AndroidUiAutomator(String),
+}
+
+impl Selector {
+    /// WebDriver locator strategy name
+    pub fn strategy(&self) -> &'static str {
+        match self {
+            Selector::Css(_) => "css selector",
+            Selector::AccessibilityId(_) => "accessibility id",
+            Selector::IosClassChain(_) => "-ios class chain",
+            Selector::AndroidUiAutomator(_) => "-android uiautomator",
+        }
+    }
+
+    /// Selector value passed to the strategy
+    pub fn value(&self) -> &str {
+        match self {
+            Selector::Css(value)
+            | Selector::AccessibilityId(value)
+            | Selector::IosClassChain(value)
+            | Selector::AndroidUiAutomator(value) => value,
+        }
+    }
+
+    /// Raw WebDriver locator for this selector
+    fn locator(&self) -> Locator {
+        Locator::new(self.strategy(), self.value())
+    }
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.strategy(), self.value())
+    }
+}
+
+/// Find elements with a [`Selector`] of any strategy
+///
+/// Implemented for [`WebDriver`] to search the whole page and for
+/// [`WebElement`] to search its descendants.
+#[async_trait]
+pub trait Locate {
+    /// Find the first element matching the selector
+    ///
+    /// # Errors
+    ///
+    /// * `UtamError::WebDriver` - When no element matches or the driver fails
+    async fn locate(&self, selector: &Selector) -> UtamResult<WebElement>;
+
+    /// Find all elements matching the selector
+    ///
+    /// # Errors
+    ///
+    /// * `UtamError::WebDriver` - When the driver fails
+    async fn locate_all(&self, selector: &Selector) -> UtamResult<Vec<WebElement>>;
+}
+
+#[async_trait]
+impl Locate for WebElement {
+    async fn locate(&self, selector: &Selector) -> UtamResult<WebElement> {
+        let command = Command::FindElementFromElement(self.element_id.clone(), selector.locator());
+        Ok(self.handle.cmd(command).await?.element(self.handle.clone())?)
+    }
+
+    async fn locate_all(&self, selector: &Selector) -> UtamResult<Vec<WebElement>> {
+        let command = Command::FindElementsFromElement(self.element_id.clone(), selector.locator());
+        Ok(self.handle.cmd(command).await?.elements(self.handle.clone())?)
+    }
+}
+
+#[async_trait]
+impl Locate for WebDriver {
+    async fn locate(&self, selector: &Selector) -> UtamResult<WebElement> {
+        let command = Command::FindElement(selector.locator());
+        Ok(self.handle.cmd(command).await?.element(self.handle.clone())?)
+    }
+
+    async fn locate_all(&self, selector: &Selector) -> UtamResult<Vec<WebElement>> {
+        let command = Command::FindElements(selector.locator());
+        Ok(self.handle.cmd(command).await?.elements(self.handle.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_names() {
+        assert_eq!(Selector::Css(".a".into()).strategy(), "css selector");
+        assert_eq!(Selector::AccessibilityId("a".into()).strategy(), "accessibility id");
+        assert_eq!(Selector::IosClassChain("a".into()).strategy(), "-ios class chain");
+        assert_eq!(Selector::AndroidUiAutomator("a".into()).strategy(), "-android uiautomator");
+    }
+
+    #[test]
+    fn test_locator_uses_strategy_and_value() {
+        let locator = Selector::AccessibilityId("Submit".into()).locator();
+        assert_eq!(&*locator.name, "accessibility id");
+        assert_eq!(&*locator.query, "Submit");
+    }
+
+    #[test]
+    fn test_display() {
+        let selector = Selector::IosClassChain("**/XCUIElementTypeButton".into());
+        assert_eq!(selector.to_string(), "-ios class chain(**/XCUIElementTypeButton)");
+    }
+}
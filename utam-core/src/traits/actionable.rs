@@ -11,7 +11,7 @@ use crate::error::UtamResult;
 /// Base trait for actionable elements
 ///
 /// Provides methods for focus, blur, scroll, and move operations.
-/// All other interaction traits (Clickable, Editable, Draggable) extend this.
+/// All other interaction traits (Clickable, Editable, Draggable, Touchable) extend this.
 #[async_trait]
 pub trait Actionable: Send + Sync {
     /// Get the underlying WebElement
@@ -9,6 +9,7 @@
 //!   - [`Clickable`] - Click operations
 //!   - [`Editable`] - Text input operations
 //!   - [`Draggable`] - Drag-and-drop operations
+//!   - [`Touchable`] - Touch gestures (tap, long press, flick, swipe)
 //! - [`PageObject`] - Base trait for all page objects
 //!   - [`RootPageObject`] - Page objects that can be loaded directly
 
@@ -17,9 +18,11 @@ mod clickable;
 mod draggable;
 mod editable;
 mod page_object;
+mod touchable;
 
 pub use actionable::Actionable;
 pub use clickable::Clickable;
 pub use draggable::Draggable;
 pub use editable::{Editable, Key};
 pub use page_object::{PageObject, RootPageObject};
+pub use touchable::Touchable;
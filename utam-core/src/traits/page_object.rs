@@ -30,10 +30,11 @@ pub trait PageObject: Sized + Send + Sync {
 /// an existing element.
 #[async_trait]
 pub trait RootPageObject: PageObject {
-    /// The CSS selector for the root element
+    /// The selector for the root element
     ///
     /// This selector is used by `load()` to find the page object's root
-    /// element in the current page.
+    /// element in the current page. It is a CSS selector unless the page
+    /// object declares a mobile strategy for its root.
     const ROOT_SELECTOR: &'static str;
 
     /// Load the page object from the current page
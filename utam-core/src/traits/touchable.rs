@@ -0,0 +1,169 @@
+//! Trait for touchable elements
+//!
+//! Extends Actionable with touch gestures, performed as W3C pointer actions
+//! with a `touch` pointer type.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use thirtyfour::common::action::{
+    ActionSource, MouseButton, PointerAction, PointerActionType, PointerOrigin,
+};
+use thirtyfour::common::command::{Actions, Command};
+use thirtyfour::{ElementId, WebElement};
+
+use crate::error::UtamResult;
+use crate::traits::Actionable;
+
+/// Duration of the movement of a flick
+const FLICK_DURATION: Duration = Duration::from_millis(100);
+
+/// Trait for touchable elements
+///
+/// Extends Actionable with touch gestures including tap, long press,
+/// flick and swipe. Every gesture starts with a finger touching the
+/// center of the element.
+#[async_trait]
+pub trait Touchable: Actionable {
+    /// Tap this element
+    async fn tap(&self) -> UtamResult<()> {
+        touch(self.inner(), Vec::new()).await
+    }
+
+    /// Press this element for a duration, then release it
+    async fn long_press(&self, duration: Duration) -> UtamResult<()> {
+        touch(self.inner(), long_press_gesture(duration)).await
+    }
+
+    /// Flick from this element by a pixel offset
+    ///
+    /// A flick moves quickly, so scrollable content keeps moving after
+    /// the finger is lifted.
+    async fn flick(&self, x: i64, y: i64) -> UtamResult<()> {
+        self.swipe(x, y, FLICK_DURATION).await
+    }
+
+    /// Swipe from this element by a pixel offset over a duration
+    async fn swipe(&self, x: i64, y: i64, duration: Duration) -> UtamResult<()> {
+        touch(self.inner(), swipe_gesture(x, y, duration)).await
+    }
+}
+
+/// Hold the finger still for `duration`
+fn long_press_gesture(duration: Duration) -> Vec<PointerAction> {
+    vec![PointerAction::Pause { duration: millis(duration) }]
+}
+
+/// Move the finger by a pixel offset over `duration`
+fn swipe_gesture(x: i64, y: i64, duration: Duration) -> Vec<PointerAction> {
+    vec![PointerAction::PointerMove {
+        duration: millis(duration),
+        origin: PointerOrigin::Pointer,
+        x,
+        y,
+    }]
+}
+
+/// Touch the center of an element, perform a gesture and lift the finger
+async fn touch(element: &WebElement, gesture: Vec<PointerAction>) -> UtamResult<()> {
+    let actions = Actions::from(touch_actions(element.element_id(), gesture));
+    element.handle.cmd(Command::PerformActions(actions)).await?;
+    Ok(())
+}
+
+/// W3C action sequence of a touch gesture on the element `element_id`
+fn touch_actions(element_id: ElementId, gesture: Vec<PointerAction>) -> serde_json::Value {
+    let mut finger = ActionSource::<PointerAction>::new("finger", PointerActionType::Touch, None);
+    finger.add_action(PointerAction::PointerMove {
+        duration: 0,
+        origin: PointerOrigin::WebElement(element_id),
+        x: 0,
+        y: 0,
+    });
+    finger.add_action(PointerAction::PointerDown { button: MouseButton::Left, duration: 0 });
+    for action in gesture {
+        finger.add_action(action);
+    }
+    finger.add_action(PointerAction::PointerUp { button: MouseButton::Left, duration: 0 });
+    serde_json::json!([finger])
+}
+
+/// Milliseconds of a duration, as W3C actions expect
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Action sequence of a touch on element `e1` with `gesture` between
+    /// pressing and lifting the finger
+    fn expected_touch(gesture: Vec<Value>) -> Value {
+        let mut actions = vec![
+            json!({
+                "type": "pointerMove",
+                "duration": 0,
+                "origin": { "element-6066-11e4-a52e-4f735466cecf": "e1" },
+                "x": 0,
+                "y": 0
+            }),
+            json!({ "type": "pointerDown", "button": 0, "duration": 0 }),
+        ];
+        actions.extend(gesture);
+        actions.push(json!({ "type": "pointerUp", "button": 0, "duration": 0 }));
+        json!([{
+            "id": "finger",
+            "type": "pointer",
+            "parameters": { "pointerType": "touch" },
+            "actions": actions
+        }])
+    }
+
+    #[test]
+    fn test_tap_actions() {
+        let actions = touch_actions(ElementId::from("e1"), Vec::new());
+        assert_eq!(actions, expected_touch(Vec::new()));
+    }
+
+    #[test]
+    fn test_long_press_actions() {
+        let gesture = long_press_gesture(Duration::from_millis(1500));
+        let actions = touch_actions(ElementId::from("e1"), gesture);
+        assert_eq!(actions, expected_touch(vec![json!({ "type": "pause", "duration": 1500 })]));
+    }
+
+    #[test]
+    fn test_swipe_actions() {
+        let gesture = swipe_gesture(-200, 50, Duration::from_millis(800));
+        let actions = touch_actions(ElementId::from("e1"), gesture);
+        assert_eq!(
+            actions,
+            expected_touch(vec![json!({
+                "type": "pointerMove",
+                "duration": 800,
+                "origin": "pointer",
+                "x": -200,
+                "y": 50
+            })])
+        );
+    }
+
+    #[test]
+    fn test_flick_actions() {
+        let gesture = swipe_gesture(0, 400, FLICK_DURATION);
+        let actions = touch_actions(ElementId::from("e1"), gesture);
+        assert_eq!(
+            actions,
+            expected_touch(vec![json!({
+                "type": "pointerMove",
+                "duration": 100,
+                "origin": "pointer",
+                "x": 0,
+                "y": 400
+            })])
+        );
+    }
+}
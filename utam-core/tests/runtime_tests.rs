@@ -90,11 +90,12 @@ fn test_prelude_exports_error_types() {
 #[test]
 fn test_prelude_exports_traits() {
     fn _check_traits_exported() {
-        use utam_core::prelude::{Actionable, Clickable, Draggable, Editable};
+        use utam_core::prelude::{Actionable, Clickable, Draggable, Editable, Touchable};
         fn _takes_actionable(_: &dyn Actionable) {}
         fn _takes_clickable(_: &dyn Clickable) {}
         fn _takes_editable(_: &dyn Editable) {}
         fn _takes_draggable(_: &dyn Draggable) {}
+        fn _takes_touchable(_: &dyn Touchable) {}
     }
 }
 
@@ -127,13 +128,34 @@ fn test_prelude_exports_shadow_types() {
     }
 }
 
+#[test]
+fn test_prelude_exports_document() {
+    fn _check_document(driver: &utam_core::prelude::WebDriver, root: &WebElement) {
+        use utam_core::prelude::Document;
+        let _document: Document = Document::new(driver);
+        let _document: Document = Document::of(root);
+    }
+}
+
 #[test]
 fn test_prelude_exports_element_wrappers() {
     fn _check_element_wrappers() {
-        use utam_core::prelude::{ClickableElement, DraggableElement, EditableElement};
+        use utam_core::prelude::{
+            ClickableElement, DraggableElement, EditableElement, TouchableElement,
+        };
         let _: Option<ClickableElement> = None;
         let _: Option<EditableElement> = None;
         let _: Option<DraggableElement> = None;
+        let _: Option<TouchableElement> = None;
+    }
+}
+
+#[test]
+fn test_prelude_exports_element_list() {
+    fn _check_element_list() {
+        use utam_core::prelude::{BaseElement, ElementList};
+        let list: ElementList<BaseElement> = ElementList::new(Vec::new());
+        assert!(list.is_empty());
     }
 }
 
@@ -470,6 +492,11 @@ fn test_draggable_trait_is_object_safe() {
     fn _assert_object_safe(_: &dyn Draggable) {}
 }
 
+#[test]
+fn test_touchable_trait_is_object_safe() {
+    fn _assert_object_safe(_: &dyn Touchable) {}
+}
+
 // ========== Module Path Tests ==========
 
 #[test]
@@ -525,6 +552,14 @@ fn test_prelude_exports_frame_context() {
     }
 }
 
+#[test]
+fn test_prelude_exports_framed() {
+    fn _check_prelude_has_framed() {
+        use utam_core::prelude::{FrameElement, Framed};
+        let _ = std::any::type_name::<Framed<FrameElement>>();
+    }
+}
+
 #[test]
 fn test_frame_element_api_exists() {
     fn _check_api_exists() {